@@ -0,0 +1,176 @@
+use std::sync::Arc;
+use arrow_array::{Array, ArrayRef, Date32Array, Date64Array, Float64Array, RecordBatch, types::{Date32Type, Date64Type}};
+use arrow_schema::{DataType, Field, Schema};
+use chrono::NaiveDate;
+use crate::error::Error;
+use crate::{SeriesCode, schemas::{IADBSeries, IADBDataPoint}, panel::{IADBPanel, JoinType}};
+
+
+/// Name of the date column of the record batches.
+pub const DATE_COLUMN: &str = "DATE";
+
+
+impl IADBPanel {
+
+    /// Converts the panel into a record batch with a `DATE` column (`Date32`) and one nullable `Float64` column per series.
+    pub fn to_record_batch(&self) -> Result<RecordBatch, Error> {
+        let mut fields: Vec<Field> = vec![Field::new(DATE_COLUMN, DataType::Date32, false)];
+        let dates: Date32Array = self.dates().iter().map(|v| Some(Date32Type::from_naive_date(*v)) ).collect();
+        let mut columns: Vec<ArrayRef> = vec![Arc::new(dates)];
+        for name in self.names() {
+            fields.push(Field::new(name, DataType::Float64, true));
+            let values: Float64Array = self.column_by_name(name).unwrap_or_default().iter().copied().collect();
+            columns.push(Arc::new(values));
+        }
+        Ok(RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?)
+    }
+
+    /// Creates a panel from a record batch with a date column (`Date32` or `Date64`) and numeric value columns.
+    ///
+    /// Note: The date column is the `DATE` column (case-insensitive) or, if there is none, the first date column.
+    /// Value columns are cast to `Float64`, and columns of other types are ignored.
+    pub fn from_record_batch(batch: &RecordBatch) -> Result<Self, Error> {
+        let schema = batch.schema();
+        let date_index: usize = schema.fields().iter().position(|v| v.name().eq_ignore_ascii_case(DATE_COLUMN) )
+            .or_else(|| schema.fields().iter().position(|v| matches!(v.data_type(), DataType::Date32 | DataType::Date64) ) )
+            .ok_or(Error::MissingColumn { series_code: String::from(DATE_COLUMN), })?;
+        let dates: Vec<NaiveDate> = date_values(batch.column(date_index))?;
+        let mut columns: Vec<(String, Vec<Option<f64>>)> = Vec::new();
+        for (i, field) in schema.fields().iter().enumerate() {
+            if i == date_index || !field.data_type().is_numeric() {
+                continue;
+            }
+            let array: ArrayRef = cast_to_float(batch.column(i))?;
+            let values: &Float64Array = array.as_any().downcast_ref::<Float64Array>()
+                .ok_or(Error::MissingColumn { series_code: field.name().clone(), })?;
+            columns.push((field.name().clone(), values.iter().collect()));
+        }
+        Ok(IADBPanel::from_columns(dates, columns))
+    }
+}
+
+
+impl IADBSeries {
+
+    /// Converts the series into a record batch with a `DATE` column (`Date32`) and a nullable `Float64` column named after the series.
+    pub fn to_record_batch(&self) -> Result<RecordBatch, Error> {
+        to_record_batch(std::slice::from_ref(self))
+    }
+}
+
+
+/// Converts the series into a record batch with a `DATE` column (`Date32`) and one nullable `Float64` column per series,
+/// matching the dates with an outer join.
+///
+/// # Examples
+///
+/// ```rust
+/// use iadb_api::{NaiveDate, IADBSeries, IADBDataPoint, arrow};
+///
+/// let date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+/// let series: IADBSeries = IADBSeries {
+///     name: String::from("IUDSOIA"), description: String::new(), data: vec![IADBDataPoint { date, value: Some(5.19) }], metadata: None,
+/// };
+///
+/// let batch = arrow::to_record_batch(&[series]).unwrap();
+///
+/// assert_eq!(batch.num_columns(), 2);
+/// assert_eq!(arrow::from_record_batch(&batch).unwrap()[0].data[0].value, Some(5.19));
+/// ```
+pub fn to_record_batch(series: &[IADBSeries]) -> Result<RecordBatch, Error> {
+    IADBPanel::join(series, JoinType::Outer).to_record_batch()
+}
+
+
+/// Converts a record batch with a date column and numeric value columns back into one series per value column.
+///
+/// Note: Null values are left out of the series, and the descriptions are taken from `SeriesCode` for the known series codes.
+pub fn from_record_batch(batch: &RecordBatch) -> Result<Vec<IADBSeries>, Error> {
+    let panel: IADBPanel = IADBPanel::from_record_batch(batch)?;
+    let series: Vec<IADBSeries> = panel.names().into_iter().map(|name| {
+        let values: &[Option<f64>] = panel.column_by_name(name).unwrap_or_default();
+        let data: Vec<IADBDataPoint> = panel.dates().iter().zip(values)
+            .filter_map(|(date, value)| value.map(|v| IADBDataPoint { date: *date, value: Some(v), } ) )
+            .collect();
+        IADBSeries { name: String::from(name), description: SeriesCode::from(name).description(), data, metadata: None, }
+    }).collect();
+    Ok(series)
+}
+
+
+/// Dates of a `Date32` or `Date64` column.
+fn date_values(array: &ArrayRef) -> Result<Vec<NaiveDate>, Error> {
+    let dates: Option<Vec<Option<NaiveDate>>> = match array.data_type() {
+        DataType::Date32 => array.as_any().downcast_ref::<Date32Array>().map(|a| a.iter().map(|v| v.and_then(Date32Type::to_naive_date_opt) ).collect() ),
+        DataType::Date64 => array.as_any().downcast_ref::<Date64Array>().map(|a| a.iter().map(|v| v.and_then(Date64Type::to_naive_date_opt) ).collect() ),
+        _ => None,
+    };
+    dates.and_then(|v| v.into_iter().collect() ).ok_or(Error::MissingColumn { series_code: String::from(DATE_COLUMN), })
+}
+
+
+/// Casts a numeric column to `Float64`.
+fn cast_to_float(array: &ArrayRef) -> Result<ArrayRef, Error> {
+    if array.data_type() == &DataType::Float64 {
+        return Ok(array.clone());
+    }
+    let values: Float64Array = (0..array.len()).map(|i| numeric_value(array, i) ).collect();
+    Ok(Arc::new(values))
+}
+
+
+/// Value of a numeric column as `f64`.
+fn numeric_value(array: &ArrayRef, index: usize) -> Option<f64> {
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float32Type, Int8Type, Int16Type, Int32Type, Int64Type, UInt8Type, UInt16Type, UInt32Type, UInt64Type};
+    if array.is_null(index) {
+        return None;
+    }
+    match array.data_type() {
+        DataType::Float32 => Some(array.as_primitive::<Float32Type>().value(index) as f64),
+        DataType::Int8 => Some(array.as_primitive::<Int8Type>().value(index) as f64),
+        DataType::Int16 => Some(array.as_primitive::<Int16Type>().value(index) as f64),
+        DataType::Int32 => Some(array.as_primitive::<Int32Type>().value(index) as f64),
+        DataType::Int64 => Some(array.as_primitive::<Int64Type>().value(index) as f64),
+        DataType::UInt8 => Some(array.as_primitive::<UInt8Type>().value(index) as f64),
+        DataType::UInt16 => Some(array.as_primitive::<UInt16Type>().value(index) as f64),
+        DataType::UInt32 => Some(array.as_primitive::<UInt32Type>().value(index) as f64),
+        DataType::UInt64 => Some(array.as_primitive::<UInt64Type>().value(index) as f64),
+        _ => None,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    #[test]
+    fn unit_test_record_batch() {
+        use std::sync::Arc;
+        use arrow_array::{ArrayRef, Date32Array, Int32Array, RecordBatch, types::Date32Type};
+        use crate::{SeriesCode, arrow, schemas::{IADBSeries, IADBDataPoint}};
+        let d = |day: u32| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+        let sonia: IADBSeries = IADBSeries {
+            name: String::from("IUDSOIA"), description: String::new(), metadata: None,
+            data: vec![IADBDataPoint { date: d(2), value: Some(5.19) }, IADBDataPoint { date: d(3), value: Some(5.2) }],
+        };
+        let bank_rate: IADBSeries = IADBSeries {
+            name: String::from("IUDBEDR"), description: String::new(), metadata: None,
+            data: vec![IADBDataPoint { date: d(3), value: Some(5.25) }],
+        };
+        let batch: RecordBatch = arrow::to_record_batch(&[sonia, bank_rate]).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(2).name(), "IUDBEDR");
+        assert_eq!(batch.column(2).null_count(), 1);
+        let series: Vec<IADBSeries> = arrow::from_record_batch(&batch).unwrap();
+        assert_eq!(series[0].data.len(), 2);
+        assert_eq!(series[1].data[0].date, d(3));
+        assert_eq!(series[1].description, SeriesCode::IUDBEDR.description());
+        // Integer columns are cast and the date column is found by its type
+        let dates: ArrayRef = Arc::new(Date32Array::from(vec![Date32Type::from_naive_date(d(2))]));
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![5]));
+        let batch: RecordBatch = RecordBatch::try_from_iter(vec![("day", dates), ("IUDBEDR", values)]).unwrap();
+        assert_eq!(arrow::from_record_batch(&batch).unwrap()[0].data[0].value, Some(5.0));
+    }
+}
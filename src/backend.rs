@@ -1,29 +1,52 @@
+use std::sync::OnceLock;
+use chrono::NaiveDate;
 use crate::error::Error;
-use crate::{SeriesCode, schemas::IADBSeries, utils::{VPD, CSVF, Param, call_api_endpoint}};
+use crate::{SeriesCode, client::IADBClient, schemas::{IADBSeries, IADBSearchResult}};
 
 
+/// Client shared by the convenience methods of `IADB`.
+static DEFAULT_CLIENT: OnceLock<IADBClient> = OnceLock::new();
+
+
+/// Convenience wrapper over a default `IADBClient` that is shared across all calls.
 pub struct IADB;
 
 impl IADB {
 
+    /// Returns the default `IADBClient`, building it on the first call.
+    pub fn client() -> Result<&'static IADBClient, Error> {
+        if let Some(client) = DEFAULT_CLIENT.get() {
+            return Ok(client);
+        }
+        let client: IADBClient = IADBClient::new()?;
+        Ok(DEFAULT_CLIENT.get_or_init(|| client))
+    }
+
+    /// Sets the client used by the convenience methods of `IADB` (e.g., to point them at a different base URL).
+    ///
+    /// Note: The client can only be set before the first request, so `false` is returned if the default client already exists.
+    pub fn init_client(client: IADBClient) -> bool {
+        DEFAULT_CLIENT.set(client).is_ok()
+    }
+
     /// Makes an API request to the IADB and deserializes the response into a time series.
     /// 
     /// # Input
     /// - `series_code`: Code of the time series in the IADB.
-    /// - `date_from`: Date from which the data will be extracted (Note: Date format is `%d/%b/%Y`)
-    /// - `date_from`: Date up to which the data will be extracted (Note: Date format is `%d/%b/%Y`)
+    /// - `date_from`: Date from which the data will be extracted
+    /// - `date_to`: Date up to which the data will be extracted
     /// 
     /// # Examples
     /// 
-    /// ```rust
-    /// use iadb_api::{SeriesCode, schemas::IADBSeries, backend::IADB};
+    /// ```rust,no_run
+    /// use iadb_api::{NaiveDate, SeriesCode, schemas::IADBSeries, backend::IADB};
     /// 
     /// #[tokio::main]
     /// async fn main() -> () {
     /// 
     ///     // Parameters
-    ///     let date_from: String = String::from("01/Jan/2000");
-    ///     let date_to: String = String::from("01/Oct/2018");
+    ///     let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    ///     let date_to: NaiveDate = NaiveDate::from_ymd_opt(2018, 10, 1).unwrap();
     /// 
     ///     // Data collection
     ///     let data: IADBSeries = IADB::get_data(&SeriesCode::IUDSOIA, &date_from, &date_to).await.unwrap();
@@ -32,16 +55,64 @@ impl IADB {
     /// 
     /// }
     /// ```
-    pub async fn get_data(series_code: &SeriesCode, date_from: &String, date_to: &String) -> Result<IADBSeries, Error> {
-        // Parameters
-        let using_codes: String = String::from("Y");
-        let vfd: String = String::from("N");
-        // Request
-        let params: Vec<Param> = vec![
-            Param::DateFrom { v: &date_from }, Param::DateTo { v: &date_to }, Param::CSVF { v: &CSVF::TN }, Param::UsingCodes { v: &using_codes },
-            Param::VPD { v: &VPD::Y }, Param::VFD { v: &vfd },
-        ];
-        call_api_endpoint(&series_code.to_string(), &Some(series_code.description()), params, None).await
+    pub async fn get_data(series_code: &SeriesCode, date_from: &NaiveDate, date_to: &NaiveDate) -> Result<IADBSeries, Error> {
+        IADB::client()?.get_data(series_code, date_from, date_to).await
+    }
+
+    /// Makes a single API request to the IADB for several time series and deserializes the response into one time series per code.
+    /// 
+    /// # Input
+    /// - `series_codes`: Codes of the time series in the IADB.
+    /// - `date_from`: Date from which the data will be extracted
+    /// - `date_to`: Date up to which the data will be extracted
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust,no_run
+    /// use iadb_api::{NaiveDate, SeriesCode, schemas::IADBSeries, backend::IADB};
+    /// 
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    /// 
+    ///     // Parameters
+    ///     let series_codes: Vec<SeriesCode> = vec![SeriesCode::IUMBV34, SeriesCode::IUMBV37, SeriesCode::IUMBV42, SeriesCode::IUMBV45];
+    ///     let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    ///     let date_to: NaiveDate = NaiveDate::from_ymd_opt(2018, 10, 1).unwrap();
+    /// 
+    ///     // Data collection
+    ///     let data: Vec<IADBSeries> = IADB::get_many(&series_codes, &date_from, &date_to).await.unwrap();
+    /// 
+    ///     assert_eq!(data.len(), 4);
+    /// 
+    /// }
+    /// ```
+    pub async fn get_many(series_codes: &[SeriesCode], date_from: &NaiveDate, date_to: &NaiveDate) -> Result<Vec<IADBSeries>, Error> {
+        IADB::client()?.get_many(series_codes, date_from, date_to).await
+    }
+
+    /// Searches the IADB series catalogue and returns the listed series with their descriptions.
+    ///
+    /// # Input
+    /// - `query`: Search terms (e.g., `sonia`)
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use iadb_api::{IADBSearchResult, backend::IADB};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///
+    ///     let results: Vec<IADBSearchResult> = IADB::search("sonia").await.unwrap();
+    ///
+    ///     for result in results {
+    ///         println!("{}", result);
+    ///     }
+    ///
+    /// }
+    /// ```
+    pub async fn search(query: &str) -> Result<Vec<IADBSearchResult>, Error> {
+        IADB::client()?.search(query).await
     }
 }
 
@@ -51,13 +122,20 @@ mod tests {
     use tokio;
 
     #[tokio::test]
-    async fn unit_test_get_data() -> () {
-        use crate::{SeriesCode, schemas::IADBSeries, backend::IADB};
+    async fn unit_test_get_data() {
+        use chrono::NaiveDate;
+        use crate::{SeriesCode, IADBClient, schemas::IADBSeries, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        server.route("SeriesCodes=IUDSOIA", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn.csv"))]);
+        // Local client, so that the default client of `IADB` is left untouched by the tests
+        let client: IADBClient = server.client();
         // Parameters
-        let date_from: String = String::from("01/Jan/2000");
-        let date_to: String = String::from("01/Oct/2018");
+        let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let date_to: NaiveDate = NaiveDate::from_ymd_opt(2018, 10, 1).unwrap();
         // Data collection
-        let data: IADBSeries = IADB::get_data(&SeriesCode::IUDSOIA, &date_from, &date_to).await.unwrap();
+        let data: IADBSeries = client.get_data(&SeriesCode::IUDSOIA, &date_from, &date_to).await.unwrap();
         println!("{}", data);
+        assert_eq!(data.data.len(), 5);
+        assert!(server.requests()[0].contains("Datefrom=01/Jan/2000&Dateto=01/Oct/2018"));
     }
-}
\ No newline at end of file
+}
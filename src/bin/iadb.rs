@@ -0,0 +1,189 @@
+//! # IADB Command-Line Interface
+//!
+//! `iadb` fetches series from the Bank of England's Statistical Interactive Database (IADB) and exports them as CSV or JSON.
+//!
+//! ```text
+//! iadb fetch IUDSOIA IUDBEDR --from 2024-01-01 --to 2024-03-31 --format csv --output rates.csv
+//! iadb describe IUDSOIA
+//! iadb list --frequency monthly --category "Effective Interest Rates"
+//! ```
+use std::{fs, process::ExitCode};
+use clap::{Parser, Subcommand, ValueEnum};
+use iadb_api::{NaiveDate, SeriesCode, IADB, IADBSeries, catalog::{CatalogQuery, Frequency}, error::Error, panel::{IADBPanel, JoinType}};
+
+
+#[derive(Debug, Parser)]
+#[command(name = "iadb", version, about = "Fetch and export series from the Bank of England's Statistical Interactive Database (IADB)")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Fetches the series from the IADB and prints (or saves) them.
+    Fetch {
+        /// Series codes (e.g., IUDSOIA)
+        #[arg(required = true, value_parser = parse_series_code)]
+        codes: Vec<SeriesCode>,
+        /// Date from which the data will be extracted (YYYY-MM-DD)
+        #[arg(long)]
+        from: NaiveDate,
+        /// Date up to which the data will be extracted (YYYY-MM-DD, defaults to today)
+        #[arg(long)]
+        to: Option<NaiveDate>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Csv)]
+        format: Format,
+        /// File that the output is written to (defaults to the standard output)
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// Prints the description, frequency, units and category of the series.
+    Describe {
+        /// Series codes (e.g., IUDSOIA)
+        #[arg(required = true, value_parser = parse_series_code)]
+        codes: Vec<SeriesCode>,
+    },
+    /// Lists the known series codes with their descriptions.
+    List {
+        /// Keeps only the series with the provided frequency
+        #[arg(long, value_parser = parse_frequency)]
+        frequency: Option<Frequency>,
+        /// Keeps only the series whose category hierarchy starts with the provided categories (e.g., --category Yields)
+        #[arg(long)]
+        category: Vec<String>,
+    },
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One row per date and one column per series
+    Csv,
+    /// Array of series with their data points
+    Json,
+}
+
+
+fn parse_series_code(s: &str) -> Result<SeriesCode, Error> {
+    s.parse()
+}
+
+
+fn parse_frequency(s: &str) -> Result<Frequency, String> {
+    [Frequency::Daily, Frequency::Weekly, Frequency::Monthly, Frequency::Quarterly, Frequency::Annual].into_iter()
+        .find(|v| v.to_string().eq_ignore_ascii_case(s) )
+        .ok_or_else(|| format!("unknown frequency `{}` (expected daily, weekly, monthly, quarterly or annual)", s) )
+}
+
+
+/// Formats the series as a CSV table with a `DATE` column and one column per series (matched with an outer join).
+fn to_csv(series: &[IADBSeries]) -> Result<String, Error> {
+    let panel: IADBPanel = IADBPanel::join(series, JoinType::Outer);
+    let mut writer = csv::Writer::from_writer(vec![]);
+    let mut header: Vec<&str> = vec!["DATE"];
+    header.extend(panel.names());
+    writer.write_record(&header)?;
+    for (date, values) in panel.rows() {
+        let mut record: Vec<String> = vec![date.to_string()];
+        record.extend(values.iter().map(|v| v.map(|v| v.to_string() ).unwrap_or_default() ));
+        writer.write_record(&record)?;
+    }
+    let bytes: Vec<u8> = writer.into_inner().map_err(|e| e.into_error() )?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+
+/// Formats the series as a JSON array.
+fn to_json(series: &[IADBSeries]) -> Result<String, Error> {
+    Ok(serde_json::to_string_pretty(series)?)
+}
+
+
+async fn run(cli: Cli) -> Result<(), Error> {
+    match cli.command {
+        Command::Fetch { codes, from, to, format, output } => {
+            let to: NaiveDate = to.unwrap_or_else(|| chrono::Local::now().date_naive() );
+            let mut series: Vec<IADBSeries> = Vec::with_capacity(codes.len());
+            for code in codes.iter() {
+                series.push(IADB::get_data(code, &from, &to).await?);
+            }
+            let body: String = match format {
+                Format::Csv => to_csv(&series)?,
+                Format::Json => to_json(&series)?,
+            };
+            match output {
+                Some(path) => fs::write(path, body)?,
+                None => print!("{}", body),
+            }
+        },
+        Command::Describe { codes } => {
+            for code in codes.iter() {
+                println!("{}: {}", code, code.description());
+                match code.catalog_entry() {
+                    Some(entry) => {
+                        println!("  Frequency: {}", entry.frequency());
+                        println!("  Units: {}", entry.units());
+                        println!("  Category: {}", entry.category_path().join(" > "));
+                    },
+                    None => println!("  Not in the catalog of known series codes"),
+                }
+            }
+        },
+        Command::List { frequency, category } => {
+            let mut query: CatalogQuery = CatalogQuery::new();
+            if let Some(frequency) = frequency {
+                query = query.frequency(frequency);
+            }
+            let category: Vec<&str> = category.iter().map(|v| v.as_str() ).collect();
+            for entry in query.category(&category).iter() {
+                println!("{}\t{}", entry.series_code(), entry.series_code().description());
+            }
+        },
+    }
+    Ok(())
+}
+
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run(Cli::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_cli() {
+        use clap::Parser;
+        use iadb_api::{NaiveDate, SeriesCode, IADBSeries, IADBDataPoint};
+        use crate::{Cli, Command, Format, to_csv};
+        let cli: Cli = Cli::try_parse_from(["iadb", "fetch", "IUDSOIA", "iudbedr", "--from", "2024-01-02", "--format", "json"]).unwrap();
+        match cli.command {
+            Command::Fetch { codes, from, to, format, .. } => {
+                assert_eq!(codes, vec![SeriesCode::IUDSOIA, SeriesCode::IUDBEDR]);
+                assert_eq!(from, NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+                assert_eq!(to, None);
+                assert_eq!(format, Format::Json);
+            },
+            _ => panic!("Expected the fetch command"),
+        }
+        assert!(Cli::try_parse_from(["iadb", "fetch", "IUDSOIA"]).is_err());
+        assert!(Cli::try_parse_from(["iadb", "list", "--frequency", "Hourly"]).is_err());
+        let d = |day: u32| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+        let series: Vec<IADBSeries> = vec![
+            IADBSeries { name: String::from("IUDSOIA"), description: String::new(), data: vec![IADBDataPoint { date: d(2), value: Some(5.19) }], metadata: None, },
+            IADBSeries { name: String::from("IUDBEDR"), description: String::new(), data: vec![IADBDataPoint { date: d(3), value: Some(5.25) }], metadata: None, },
+        ];
+        assert_eq!(to_csv(&series).unwrap(), "DATE,IUDSOIA,IUDBEDR\n2024-01-02,5.19,\n2024-01-03,,5.25\n");
+    }
+}
@@ -0,0 +1,139 @@
+//! Synchronous counterpart of `backend::IADB` for programs that do not run an async runtime (e.g., batch tools and build scripts).
+use std::{future::Future, sync::OnceLock};
+use tokio::runtime::{Builder, Runtime};
+use chrono::NaiveDate;
+use crate::error::Error;
+use crate::{SeriesCode, client::IADBClient, schemas::{IADBSeries, IADBSearchResult}};
+
+
+/// Runtime that drives the requests of the blocking methods, shared across all calls.
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+/// Client used by the blocking methods, separate from the default client of `backend::IADB`.
+static BLOCKING_CLIENT: OnceLock<IADBClient> = OnceLock::new();
+
+
+/// Returns the internal runtime, building it on the first call.
+fn runtime() -> Result<&'static Runtime, Error> {
+    if let Some(runtime) = RUNTIME.get() {
+        return Ok(runtime);
+    }
+    let runtime: Runtime = Builder::new_current_thread().enable_all().build()?;
+    Ok(RUNTIME.get_or_init(|| runtime))
+}
+
+
+/// Runs the future to completion on the internal runtime, building the runtime on the first call.
+///
+/// Note: Panics if called from within an async runtime, like `tokio::runtime::Runtime::block_on`.
+pub(crate) fn block_on<F: Future>(future: F) -> Result<F::Output, Error> {
+    Ok(runtime()?.block_on(future))
+}
+
+
+/// Blocking wrapper over a default `IADBClient`, with the same methods as `backend::IADB`.
+///
+/// Note: The default client is built inside the internal runtime and is not shared with `backend::IADB`, whose client
+/// may be driven by another runtime. The methods must not be called from within an async runtime.
+pub struct IADB;
+
+impl IADB {
+
+    /// Returns the default `IADBClient` of the blocking methods, building it inside the internal runtime on the first call.
+    pub fn client() -> Result<&'static IADBClient, Error> {
+        if let Some(client) = BLOCKING_CLIENT.get() {
+            return Ok(client);
+        }
+        let client: IADBClient = {
+            let _guard = runtime()?.enter();
+            IADBClient::new()?
+        };
+        Ok(BLOCKING_CLIENT.get_or_init(|| client))
+    }
+
+    /// Sets the client used by the blocking methods of `IADB` (e.g., to point them at a different base URL).
+    ///
+    /// Note: The client can only be set before the first request, so `false` is returned if the default client already exists.
+    /// The client of `backend::IADB` is not affected.
+    pub fn init_client(client: IADBClient) -> bool {
+        BLOCKING_CLIENT.set(client).is_ok()
+    }
+
+    /// Makes an API request to the IADB and deserializes the response into a time series, blocking until the request is finished.
+    /// 
+    /// # Input
+    /// - `series_code`: Code of the time series in the IADB.
+    /// - `date_from`: Date from which the data will be extracted
+    /// - `date_to`: Date up to which the data will be extracted
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust,no_run
+    /// use iadb_api::{NaiveDate, SeriesCode, schemas::IADBSeries, blocking::IADB};
+    /// 
+    /// fn main() -> () {
+    /// 
+    ///     // Parameters
+    ///     let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    ///     let date_to: NaiveDate = NaiveDate::from_ymd_opt(2018, 10, 1).unwrap();
+    /// 
+    ///     // Data collection
+    ///     let data: IADBSeries = IADB::get_data(&SeriesCode::IUDSOIA, &date_from, &date_to).unwrap();
+    /// 
+    ///     println!("{}", data);
+    /// 
+    /// }
+    /// ```
+    pub fn get_data(series_code: &SeriesCode, date_from: &NaiveDate, date_to: &NaiveDate) -> Result<IADBSeries, Error> {
+        let client: &IADBClient = IADB::client()?;
+        block_on(client.get_data(series_code, date_from, date_to))?
+    }
+
+    /// Makes a single API request to the IADB for several time series and deserializes the response into one time series per code,
+    /// blocking until the request is finished.
+    /// 
+    /// # Input
+    /// - `series_codes`: Codes of the time series in the IADB.
+    /// - `date_from`: Date from which the data will be extracted
+    /// - `date_to`: Date up to which the data will be extracted
+    pub fn get_many(series_codes: &[SeriesCode], date_from: &NaiveDate, date_to: &NaiveDate) -> Result<Vec<IADBSeries>, Error> {
+        let client: &IADBClient = IADB::client()?;
+        block_on(client.get_many(series_codes, date_from, date_to))?
+    }
+
+    /// Searches the IADB series catalogue and returns the listed series with their descriptions, blocking until the request is finished.
+    ///
+    /// # Input
+    /// - `query`: Search terms (e.g., `sonia`)
+    pub fn search(query: &str) -> Result<Vec<IADBSearchResult>, Error> {
+        let client: &IADBClient = IADB::client()?;
+        block_on(client.search(query))?
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_block_on() {
+        use chrono::NaiveDate;
+        use std::time::Duration;
+        use tokio::runtime::Runtime;
+        use crate::{SeriesCode, IADBClient, RetryPolicy, schemas::IADBSeries, blocking, mock::{MockServer, MockResponse}};
+        // Mock server runs on its own runtime, while the request is driven by the internal runtime of the blocking module
+        let server_runtime: Runtime = Runtime::new().unwrap();
+        let server: MockServer = server_runtime.block_on(MockServer::start());
+        server.route("SeriesCodes=IUDSOIA", vec![MockResponse::status(503), MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn.csv"))]);
+        let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let date_to: NaiveDate = NaiveDate::from_ymd_opt(2018, 10, 1).unwrap();
+        let client: IADBClient = IADBClient::builder().base_url(&server.url())
+            .retry_policy(RetryPolicy::default().initial_backoff(Duration::from_millis(10))).build().unwrap();
+        let data: IADBSeries = blocking::block_on(client.get_data(&SeriesCode::IUDSOIA, &date_from, &date_to)).unwrap().unwrap();
+        assert_eq!(data.data.len(), 5);
+        // Backoff before the retry is timed by the internal runtime
+        assert_eq!(server.requests().len(), 2);
+        // Default client of the blocking methods is not the one driven by the runtime of the caller of `backend::IADB`
+        assert!(!std::ptr::eq(blocking::IADB::client().unwrap(), crate::backend::IADB::client().unwrap()));
+    }
+}
@@ -0,0 +1,225 @@
+use std::sync::Arc;
+use futures::{Stream, StreamExt, stream};
+use chrono::NaiveDate;
+use crate::error::Error;
+use crate::{SeriesCode, client::IADBClient, schemas::IADBSeries};
+
+
+/// Number of jobs that run at the same time unless a different limit is set.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Download of a single series over a date range.
+pub struct BulkJob {
+    pub series_code: SeriesCode,
+    pub date_from: NaiveDate,
+    pub date_to: NaiveDate,
+}
+
+impl BulkJob {
+
+    /// Creates a job that downloads the series between `date_from` and `date_to`.
+    pub fn new(series_code: SeriesCode, date_from: NaiveDate, date_to: NaiveDate) -> Self {
+        BulkJob { series_code, date_from, date_to, }
+    }
+}
+
+impl From<(SeriesCode, NaiveDate, NaiveDate)> for BulkJob {
+    fn from(value: (SeriesCode, NaiveDate, NaiveDate)) -> Self {
+        BulkJob::new(value.0, value.1, value.2)
+    }
+}
+
+
+#[derive(Debug)]
+/// Outcome of a job.
+pub struct BulkResult {
+    pub job: BulkJob,
+    pub result: Result<IADBSeries, Error>,
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Progress of the bulk download, reported after every finished job.
+pub struct BulkProgress {
+    /// Number of finished jobs, including the failed ones.
+    pub completed: usize,
+    /// Number of failed jobs.
+    pub failed: usize,
+    /// Total number of jobs.
+    pub total: usize,
+}
+
+
+#[derive(Debug, Default)]
+/// Series of the successful jobs and errors of the failed jobs, in the order in which the jobs finished.
+pub struct BulkReport {
+    pub series: Vec<IADBSeries>,
+    pub errors: Vec<(BulkJob, Error)>,
+}
+
+
+/// Callback that receives the progress of the bulk download.
+type ProgressCallback = Arc<dyn Fn(BulkProgress) + Send + Sync>;
+
+
+/// Downloader that runs many jobs with a limit on the number of concurrent requests.
+///
+/// Note: The requests are sent by the client, so its retry policy, rate limiter and cache apply to every job
+/// (jobs of the same series code update the cache one after another).
+/// A failed job does not stop the other jobs, and its error is returned with the job.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use iadb_api::{NaiveDate, SeriesCode, IADBClient, bulk::{BulkDownloader, BulkReport}};
+///
+/// #[tokio::main]
+/// async fn main() -> () {
+///
+///     // Parameters
+///     let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+///     let date_to: NaiveDate = NaiveDate::from_ymd_opt(2018, 10, 1).unwrap();
+///     let jobs: Vec<(SeriesCode, NaiveDate, NaiveDate)> = SeriesCode::all().map(|v| (v, date_from, date_to) ).collect();
+///
+///     // Data collection
+///     let downloader: BulkDownloader = BulkDownloader::new(IADBClient::new().unwrap())
+///         .concurrency(8)
+///         .on_progress(|v| println!("{}/{} ({} failed)", v.completed, v.total, v.failed) );
+///     let report: BulkReport = downloader.run(jobs).await;
+///
+///     println!("{} series downloaded, {} errors", report.series.len(), report.errors.len());
+///
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BulkDownloader {
+    client: IADBClient,
+    concurrency: usize,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl BulkDownloader {
+
+    /// Creates a downloader that sends the requests with the client and runs `DEFAULT_CONCURRENCY` jobs at the same time.
+    pub fn new(client: IADBClient) -> Self {
+        BulkDownloader { client, concurrency: DEFAULT_CONCURRENCY, on_progress: None, }
+    }
+
+    /// Sets the maximum number of jobs that run at the same time (Note: A limit of `0` is treated as `1`).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets the callback that receives the progress after every finished job.
+    pub fn on_progress<F: Fn(BulkProgress) + Send + Sync + 'static>(mut self, on_progress: F) -> Self {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+
+    /// Runs the jobs and yields their outcomes as they finish (i.e., not necessarily in the order of `jobs`).
+    ///
+    /// # Input
+    /// - `jobs`: Jobs to run (e.g., `(SeriesCode, NaiveDate, NaiveDate)` tuples)
+    pub fn stream<I, J>(&self, jobs: I) -> impl Stream<Item = BulkResult> + Send + 'static
+    where
+        I: IntoIterator<Item = J>,
+        J: Into<BulkJob>,
+    {
+        let jobs: Vec<BulkJob> = jobs.into_iter().map(Into::into).collect();
+        let mut progress: BulkProgress = BulkProgress { completed: 0, failed: 0, total: jobs.len(), };
+        let client: IADBClient = self.client.clone();
+        let on_progress: Option<ProgressCallback> = self.on_progress.clone();
+        stream::iter(jobs)
+            .map(move |job| {
+                let client: IADBClient = client.clone();
+                async move {
+                    let result: Result<IADBSeries, Error> = client.get_data(&job.series_code, &job.date_from, &job.date_to).await;
+                    BulkResult { job, result, }
+                }
+            })
+            .buffer_unordered(self.concurrency)
+            .inspect(move |v| {
+                progress.completed += 1;
+                if v.result.is_err() {
+                    progress.failed += 1;
+                }
+                if let Some(on_progress) = &on_progress {
+                    on_progress(progress);
+                }
+            })
+    }
+
+    /// Runs the jobs and collects the downloaded series and the errors of the failed jobs.
+    ///
+    /// # Input
+    /// - `jobs`: Jobs to run (e.g., `(SeriesCode, NaiveDate, NaiveDate)` tuples)
+    pub async fn run<I, J>(&self, jobs: I) -> BulkReport
+    where
+        I: IntoIterator<Item = J>,
+        J: Into<BulkJob>,
+    {
+        let mut report: BulkReport = BulkReport::default();
+        let mut results = Box::pin(self.stream(jobs));
+        while let Some(BulkResult { job, result }) = results.next().await {
+            match result {
+                Ok(series) => report.series.push(series),
+                Err(e) => report.errors.push((job, e)),
+            }
+        }
+        report
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[tokio::test]
+    async fn unit_test_bulk_downloader() {
+        use std::sync::{Arc, Mutex};
+        use chrono::NaiveDate;
+        use crate::{SeriesCode, bulk::{BulkDownloader, BulkProgress, BulkReport}, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        server.route("SeriesCodes=IUDSOIA&", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn.csv"))]);
+        server.route("SeriesCodes=IUDXXXX&", vec![MockResponse::html(include_str!("../tests/fixtures/synthetic/unknown_series.html"))]);
+        let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let date_to: NaiveDate = NaiveDate::from_ymd_opt(2018, 10, 1).unwrap();
+        let jobs: Vec<(SeriesCode, NaiveDate, NaiveDate)> = vec![
+            (SeriesCode::IUDSOIA, date_from, date_to), (SeriesCode::Other(String::from("IUDXXXX")), date_from, date_to),
+            (SeriesCode::IUDSOIA, date_from, NaiveDate::from_ymd_opt(2000, 1, 31).unwrap()),
+        ];
+        let updates: Arc<Mutex<Vec<BulkProgress>>> = Arc::new(Mutex::new(vec![]));
+        let recorded: Arc<Mutex<Vec<BulkProgress>>> = updates.clone();
+        let downloader: BulkDownloader = BulkDownloader::new(server.client()).concurrency(2).on_progress(move |v| recorded.lock().unwrap().push(v) );
+        let report: BulkReport = downloader.run(jobs).await;
+        // Failed job does not stop the other jobs
+        assert_eq!(report.series.len(), 2);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].0.series_code.to_string(), "IUDXXXX");
+        assert_eq!(server.requests().len(), 3);
+        let updates: Vec<BulkProgress> = updates.lock().unwrap().clone();
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates[2], BulkProgress { completed: 3, failed: 1, total: 3, });
+    }
+
+    #[tokio::test]
+    async fn unit_test_bulk_downloader_cache() {
+        use chrono::NaiveDate;
+        use crate::{SeriesCode, IADBClient, RetryPolicy, bulk::{BulkDownloader, BulkReport}, cache::SeriesCache, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        server.route("Datefrom=11/Jan/2000", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn_update.csv"))]);
+        server.route("SeriesCodes=IUDSOIA", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn.csv"))]);
+        let cache: SeriesCache = SeriesCache::new(std::env::temp_dir().join(format!("iadb-api-bulk-cache-{}", std::process::id())));
+        let client: IADBClient = IADBClient::builder().base_url(&server.url()).retry_policy(RetryPolicy::none()).cache(cache.clone()).build().unwrap();
+        let d = |day: u32| NaiveDate::from_ymd_opt(2000, 1, day).unwrap();
+        let jobs: Vec<(SeriesCode, NaiveDate, NaiveDate)> = vec![(SeriesCode::IUDSOIA, d(1), d(10)), (SeriesCode::IUDSOIA, d(11), d(12))];
+        let report: BulkReport = BulkDownloader::new(client).concurrency(2).run(jobs).await;
+        assert!(report.errors.is_empty());
+        // Concurrent jobs of the same series code do not overwrite each other's observations in the cache
+        assert_eq!(cache.load(&SeriesCode::IUDSOIA).await.unwrap().unwrap().series.data.len(), 7);
+        std::fs::remove_dir_all(cache.directory()).unwrap();
+    }
+}
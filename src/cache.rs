@@ -0,0 +1,162 @@
+use std::{io::ErrorKind as IOErrorKind, path::{Path, PathBuf}, collections::HashMap};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, atomic::{AtomicU64, Ordering}};
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+use serde::{Serialize, Deserialize};
+use chrono::NaiveDate;
+use crate::error::Error;
+use crate::{SeriesCode, schemas::IADBSeries};
+
+
+/// Locks of the cached files, shared by all caches so that caches over the same directory do not overwrite each other's updates.
+///
+/// Note: The lock of a file is removed once no task holds or waits for it, so that the map does not grow with every series ever cached.
+static FILE_LOCKS: OnceLock<Mutex<HashMap<PathBuf, Arc<AsyncMutex<()>>>>> = OnceLock::new();
+
+/// Counter that makes the names of the temporary files unique.
+static WRITE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Series stored in the cache.
+pub struct CachedSeries {
+    /// Date from which the series has been downloaded.
+    pub date_from: NaiveDate,
+    /// Downloaded data points, sorted by date.
+    pub series: IADBSeries,
+}
+
+impl CachedSeries {
+
+    /// Date of the last cached observation.
+    pub fn last_date(&self) -> Option<NaiveDate> {
+        self.series.data.last().map(|v| v.date )
+    }
+}
+
+
+/// Guard of the lock of a cached series, returned by `SeriesCache::lock`.
+#[derive(Debug)]
+pub struct CacheLock {
+    path: PathBuf,
+    guard: Option<OwnedMutexGuard<()>>,
+}
+
+impl Drop for CacheLock {
+    fn drop(&mut self) {
+        // Guard holds a reference to the lock, so it is released before checking whether any other task still uses the lock
+        self.guard.take();
+        let mut locks = file_locks();
+        if locks.get(&self.path).is_some_and(|v| Arc::strong_count(v) == 1 ) {
+            locks.remove(&self.path);
+        }
+    }
+}
+
+
+/// Map of the locks of the cached files.
+fn file_locks() -> MutexGuard<'static, HashMap<PathBuf, Arc<AsyncMutex<()>>>> {
+    FILE_LOCKS.get_or_init(|| Mutex::new(HashMap::new()) ).lock().unwrap_or_else(|e| e.into_inner() )
+}
+
+
+/// On-disk cache of the downloaded series, with one JSON file per series code.
+///
+/// # Examples
+///
+/// ```rust
+/// use iadb_api::{IADBClient, cache::SeriesCache};
+///
+/// let cache: SeriesCache = SeriesCache::new(std::env::temp_dir().join("iadb-cache"));
+/// let client: IADBClient = IADBClient::builder().cache(cache).build().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct SeriesCache {
+    directory: PathBuf,
+}
+
+impl SeriesCache {
+
+    /// Creates a cache that stores the series in the provided directory (Note: The directory is created on the first write).
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Self {
+        SeriesCache { directory: directory.into(), }
+    }
+
+    /// Directory of the cache.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Path of the file that stores the series.
+    fn path(&self, series_code: &SeriesCode) -> PathBuf {
+        let file_name: String = series_code.to_string().chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' } ).collect();
+        self.directory.join(format!("{}.json", file_name))
+    }
+
+    /// Waits until no other task is updating the cached series, and holds the lock of the series until the guard is dropped.
+    ///
+    /// Note: The lock only coordinates the tasks of this process, and `load` and `save` do not take it themselves,
+    /// so it should be held from loading the series until saving the updated series.
+    pub async fn lock(&self, series_code: &SeriesCode) -> CacheLock {
+        let path: PathBuf = self.path(series_code);
+        let lock: Arc<AsyncMutex<()>> = file_locks().entry(path.clone()).or_default().clone();
+        CacheLock { path, guard: Some(lock.lock_owned().await), }
+    }
+
+    /// Loads the cached series, if the series has been cached.
+    pub async fn load(&self, series_code: &SeriesCode) -> Result<Option<CachedSeries>, Error> {
+        match tokio::fs::read(self.path(series_code)).await {
+            Ok(v) => Ok(Some(serde_json::from_slice(&v)?)),
+            Err(e) if e.kind() == IOErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Stores the series in the cache, replacing the previously cached version.
+    pub async fn save(&self, series_code: &SeriesCode, cached_series: &CachedSeries) -> Result<(), Error> {
+        tokio::fs::create_dir_all(&self.directory).await?;
+        let path: PathBuf = self.path(series_code);
+        // Write to a temporary file first so that an interrupted write does not corrupt the cache
+        // Name of the temporary file is unique so that concurrent writes do not interleave
+        let temporary_path: PathBuf = path.with_extension(format!("json.{}.{}.tmp", std::process::id(), WRITE_COUNTER.fetch_add(1, Ordering::Relaxed)));
+        tokio::fs::write(&temporary_path, serde_json::to_vec(cached_series)?).await?;
+        tokio::fs::rename(&temporary_path, &path).await?;
+        Ok(())
+    }
+
+    /// Removes the series from the cache.
+    pub async fn remove(&self, series_code: &SeriesCode) -> Result<(), Error> {
+        match tokio::fs::remove_file(self.path(series_code)).await {
+            Err(e) if e.kind() != IOErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    #[tokio::test]
+    async fn unit_test_cache() {
+        use crate::{SeriesCode, cache::{SeriesCache, CachedSeries, file_locks}, schemas::{IADBSeries, IADBDataPoint}};
+        let cache: SeriesCache = SeriesCache::new(std::env::temp_dir().join(format!("iadb-api-cache-{}", std::process::id())));
+        let date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        let cached_series: CachedSeries = CachedSeries {
+            date_from: date,
+            series: IADBSeries { name: String::from("IUDSOIA"), description: String::new(), data: vec![IADBDataPoint { date, value: Some(5.19) }], metadata: None, },
+        };
+        assert!(cache.load(&SeriesCode::IUDSOIA).await.unwrap().is_none());
+        cache.save(&SeriesCode::IUDSOIA, &cached_series).await.unwrap();
+        let loaded: CachedSeries = cache.load(&SeriesCode::IUDSOIA).await.unwrap().unwrap();
+        assert_eq!(loaded.last_date(), Some(date));
+        cache.remove(&SeriesCode::IUDSOIA).await.unwrap();
+        assert!(cache.load(&SeriesCode::IUDSOIA).await.unwrap().is_none());
+        // Lock of the series is only kept while it is held
+        let lock = cache.lock(&SeriesCode::IUDSOIA).await;
+        assert!(file_locks().contains_key(&cache.path(&SeriesCode::IUDSOIA)));
+        drop(lock);
+        assert!(!file_locks().contains_key(&cache.path(&SeriesCode::IUDSOIA)));
+        std::fs::remove_dir_all(cache.directory()).unwrap();
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use chrono::{NaiveDate, Datelike, Days, Months};
+use crate::{SeriesCode, CATALOG};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+/// Frequency of the observations in a series.
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Quarterly,
+    Annual,
+}
+
+impl Frequency {
+
+    /// Last day of the period that contains the date, which is the date the IADB uses for the observations of the period
+    /// (e.g., `31 Jan 2000` for January 2000).
+    ///
+    /// Note: Weeks end on Sunday, and quarters end in March, June, September and December.
+    pub fn period_end(&self, date: &NaiveDate) -> NaiveDate {
+        let first_month: u32 = match self {
+            Frequency::Daily => return *date,
+            Frequency::Weekly => return *date + Days::new(6 - date.weekday().num_days_from_monday() as u64),
+            Frequency::Monthly => date.month(),
+            Frequency::Quarterly => 3 * ((date.month() - 1) / 3) + 1,
+            Frequency::Annual => 1,
+        };
+        let months: u32 = match self {
+            Frequency::Quarterly => 3,
+            Frequency::Annual => 12,
+            _ => 1,
+        };
+        // Day before the first day of the next period
+        let period_start: NaiveDate = NaiveDate::from_ymd_opt(date.year(), first_month, 1).unwrap_or(*date);
+        period_start + Months::new(months) - Days::new(1)
+    }
+
+    /// Last day of the period that follows the period that contains the date.
+    pub fn next_period_end(&self, date: &NaiveDate) -> NaiveDate {
+        self.period_end(&(self.period_end(date) + Days::new(1)))
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Frequency::Daily => write!(f, "Daily"),
+            Frequency::Weekly => write!(f, "Weekly"),
+            Frequency::Monthly => write!(f, "Monthly"),
+            Frequency::Quarterly => write!(f, "Quarterly"),
+            Frequency::Annual => write!(f, "Annual"),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Units of the values in a series.
+pub enum Units {
+    /// Interest rates and yields, in percent.
+    Percent,
+    /// Exchange rates, in units of the foreign currency per one pound sterling.
+    CurrencyPerSterling,
+    /// Forward premium/discount, in US cents.
+    UsCents,
+    /// Price of gold, in pounds sterling per troy ounce.
+    SterlingPerTroyOunce,
+    /// Price of gold, in US dollars per troy ounce.
+    UsDollarsPerTroyOunce,
+}
+
+impl fmt::Display for Units {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Units::Percent => write!(f, "Percent"),
+            Units::CurrencyPerSterling => write!(f, "Currency per £"),
+            Units::UsCents => write!(f, "US cents"),
+            Units::SterlingPerTroyOunce => write!(f, "£ per troy ounce"),
+            Units::UsDollarsPerTroyOunce => write!(f, "US$ per troy ounce"),
+        }
+    }
+}
+
+
+#[derive(Debug)]
+/// Structured description of a series code in the catalog.
+pub struct CatalogEntry {
+    series_code: SeriesCode,
+    frequency: Frequency,
+    units: Units,
+    category: &'static [&'static str],
+}
+
+impl CatalogEntry {
+
+    /// Creates an entry of the catalog (Note: Entries are generated by the `series_codes!` table in the crate root).
+    pub(crate) const fn new(series_code: SeriesCode, frequency: Frequency, units: Units, category: &'static [&'static str]) -> Self {
+        CatalogEntry { series_code, frequency, units, category, }
+    }
+
+    /// Series code of the entry.
+    pub fn series_code(&self) -> &SeriesCode {
+        &self.series_code
+    }
+
+    /// Frequency of the observations.
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Units of the values.
+    pub fn units(&self) -> Units {
+        self.units
+    }
+
+    /// Category hierarchy of the series, from the broadest category to the most specific one
+    /// (e.g., `["Effective Interest Rates", "Outstanding sterling deposits with ...", "Households", "Time", "Total"]`).
+    pub fn category_path(&self) -> &'static [&'static str] {
+        self.category
+    }
+
+    /// Top-level category of the series (e.g., `Effective Interest Rates`).
+    pub fn category(&self) -> &'static str {
+        self.category[0]
+    }
+
+    /// Second-level category of the series, if any.
+    pub fn subcategory(&self) -> Option<&'static str> {
+        self.category.get(1).copied()
+    }
+
+    /// Checks whether the category hierarchy of the series starts with the provided categories (case-insensitive).
+    pub fn in_category(&self, path: &[&str]) -> bool {
+        path.len() <= self.category.len() && path.iter().zip(self.category.iter()).all(|(a, b)| a.eq_ignore_ascii_case(b) )
+    }
+}
+
+
+/// Query over the catalog that filters the entries by their structured fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use iadb_api::catalog::{CatalogQuery, Frequency};
+///
+/// let codes: Vec<String> = CatalogQuery::new()
+///     .frequency(Frequency::Monthly)
+///     .category(&["Quoted household interest rates", "Deposit rates"])
+///     .iter().map(|v| v.series_code().to_string() ).collect();
+///
+/// assert!(codes.contains(&String::from("IUMB6VJ")));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CatalogQuery {
+    frequency: Option<Frequency>,
+    units: Option<Units>,
+    category: Vec<String>,
+}
+
+impl CatalogQuery {
+
+    /// Creates a query that matches all entries of the catalog.
+    pub fn new() -> Self {
+        CatalogQuery::default()
+    }
+
+    /// Keeps only the entries with the provided frequency.
+    pub fn frequency(mut self, frequency: Frequency) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Keeps only the entries with the provided units.
+    pub fn units(mut self, units: Units) -> Self {
+        self.units = Some(units);
+        self
+    }
+
+    /// Keeps only the entries whose category hierarchy starts with the provided categories (case-insensitive).
+    pub fn category(mut self, path: &[&str]) -> Self {
+        self.category = path.iter().map(|v| String::from(*v) ).collect();
+        self
+    }
+
+    /// Checks whether the entry matches the query.
+    pub fn matches(&self, entry: &CatalogEntry) -> bool {
+        let path: Vec<&str> = self.category.iter().map(|v| v.as_str() ).collect();
+        self.frequency.is_none_or(|v| v == entry.frequency ) && self.units.is_none_or(|v| v == entry.units ) && entry.in_category(&path)
+    }
+
+    /// Iterates over the entries of the catalog that match the query.
+    pub fn iter(&self) -> impl Iterator<Item = &'static CatalogEntry> + '_ {
+        entries().filter(|v| self.matches(v) )
+    }
+}
+
+
+/// Iterates over all entries of the catalog, in the order of the `SeriesCode` variants.
+pub fn entries() -> impl Iterator<Item = &'static CatalogEntry> {
+    CATALOG.iter()
+}
+
+
+/// Finds the catalog entry of the series code (Note: `SeriesCode::Other` has no entry).
+pub fn find(series_code: &SeriesCode) -> Option<&'static CatalogEntry> {
+    CATALOG.iter().find(|v| &v.series_code == series_code )
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_catalog() {
+        use crate::{SeriesCode, catalog::{self, CatalogQuery, Frequency, Units}};
+        assert_eq!(catalog::entries().count(), 229);
+        assert_eq!(SeriesCode::IUDSOIA.frequency(), Some(Frequency::Daily));
+        assert_eq!(SeriesCode::CFMB2CX.frequency(), Some(Frequency::Monthly));
+        assert_eq!(SeriesCode::XUDLUSS.units(), Some(Units::CurrencyPerSterling));
+        assert_eq!(SeriesCode::Other(String::from("IUDXXXX")).frequency(), None);
+        let path: &[&str] = SeriesCode::CFMHSCV.category_path().unwrap();
+        assert_eq!(path[0], "Effective Interest Rates");
+        assert_eq!(path[2], "Households");
+        assert_eq!(CatalogQuery::new().frequency(Frequency::Quarterly).iter().count(), 30);
+        let query: CatalogQuery = CatalogQuery::new().category(&["yields", "British Government Securities (calculated using VRP model)", "Zero coupon yields"]);
+        let codes: Vec<String> = query.iter().map(|v| v.series_code().to_string() ).collect();
+        assert_eq!(codes, vec!["IUDSIZC", "IUDMIZC", "IUDLIZC"]);
+    }
+}
@@ -0,0 +1,646 @@
+use std::{sync::Arc, time::Duration};
+use reqwest::Client;
+use futures::{StreamExt, stream};
+use chrono::{NaiveDate, Days};
+use crate::error::Error;
+use crate::{BASE_URL, SEARCH_URL, SeriesCode, schemas::{IADBSeries, IADBSearchResult}, search::call_search_endpoint, retry::{RetryPolicy, RateLimiter}, transport::{Transport, ReqwestTransport}, cache::{SeriesCache, CachedSeries}, utils::{VPD, CSVF, Param, MissingValuePolicy, RequestOptions, call_api_endpoint, call_api_endpoint_many}};
+
+
+pub use reqwest::{Proxy, header::HeaderMap};
+
+
+/// User agent that is sent with the requests unless a custom user agent is provided.
+pub const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/54.0.2840.90 Safari/537.36";
+
+
+/// Builder of the `IADBClient`.
+#[derive(Debug)]
+pub struct IADBClientBuilder {
+    base_url: String,
+    search_url: String,
+    user_agent: String,
+    timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    default_headers: HeaderMap,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<SeriesCache>,
+    transport: Option<Arc<dyn Transport>>,
+}
+
+impl IADBClientBuilder {
+
+    /// Sets the URL of the IADB endpoint (Default: `BASE_URL`).
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = String::from(base_url);
+        self
+    }
+
+    /// Sets the URL of the IADB series search page (Default: `SEARCH_URL`).
+    pub fn search_url(mut self, search_url: &str) -> Self {
+        self.search_url = String::from(search_url);
+        self
+    }
+
+    /// Sets the user agent of the requests (Default: `DEFAULT_USER_AGENT`).
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = String::from(user_agent);
+        self
+    }
+
+    /// Sets the timeout of every request, from connecting until the response body has been received.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the proxy that all requests are sent through.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets the headers that are sent with every request.
+    pub fn default_headers(mut self, default_headers: HeaderMap) -> Self {
+        self.default_headers = default_headers;
+        self
+    }
+
+    /// Sets the policy of retrying the failed requests (Default: `RetryPolicy::default()`).
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the rate limiter that spaces out the requests sent by the client and its clones.
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sets the on-disk cache that `get_data` reads from and refreshes incrementally.
+    pub fn cache(mut self, cache: SeriesCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sets the HTTP transport that sends the requests (Default: `ReqwestTransport`).
+    ///
+    /// Note: The user agent, timeout, proxy and default headers only configure the default transport,
+    /// so they are ignored if a custom transport is set.
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Builds the `IADBClient` and its underlying connection pool.
+    pub fn build(self) -> Result<IADBClient, Error> {
+        if let Some(transport) = self.transport {
+            return Ok(IADBClient {
+                base_url: self.base_url, search_url: self.search_url, transport, retry_policy: self.retry_policy, rate_limiter: self.rate_limiter.map(Arc::new),
+                cache: self.cache,
+            });
+        }
+        let mut builder = Client::builder().user_agent(self.user_agent).default_headers(self.default_headers);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy);
+        }
+        Ok(IADBClient {
+            base_url: self.base_url, search_url: self.search_url, transport: Arc::new(ReqwestTransport::new(builder.build()?)), retry_policy: self.retry_policy, rate_limiter: self.rate_limiter.map(Arc::new),
+            cache: self.cache,
+        })
+    }
+}
+
+impl Default for IADBClientBuilder {
+    fn default() -> Self {
+        IADBClientBuilder {
+            base_url: String::from(BASE_URL), search_url: String::from(SEARCH_URL), user_agent: String::from(DEFAULT_USER_AGENT), timeout: None, proxy: None, default_headers: HeaderMap::new(),
+            retry_policy: RetryPolicy::default(), rate_limiter: None, cache: None, transport: None,
+        }
+    }
+}
+
+
+/// Client of the IADB that reuses a single connection pool across the requests.
+///
+/// Note: Cloning the client is cheap and the clones share the same connection pool.
+#[derive(Debug, Clone)]
+pub struct IADBClient {
+    base_url: String,
+    search_url: String,
+    transport: Arc<dyn Transport>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    cache: Option<SeriesCache>,
+}
+
+impl IADBClient {
+
+    /// Creates a builder of the `IADBClient`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use iadb_api::IADBClient;
+    ///
+    /// let client: IADBClient = IADBClient::builder()
+    ///     .user_agent("my-app/1.0")
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build().unwrap();
+    /// ```
+    pub fn builder() -> IADBClientBuilder {
+        IADBClientBuilder::default()
+    }
+
+    /// Creates a client with the default configuration.
+    pub fn new() -> Result<Self, Error> {
+        IADBClientBuilder::default().build()
+    }
+
+    /// URL of the IADB endpoint.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// URL of the IADB series search page.
+    pub fn search_url(&self) -> &str {
+        &self.search_url
+    }
+
+    /// HTTP transport that sends the requests.
+    pub(crate) fn transport(&self) -> &dyn Transport {
+        self.transport.as_ref()
+    }
+
+    /// Policy of retrying the failed requests.
+    pub(crate) fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Rate limiter of the requests, if any.
+    pub(crate) fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_deref()
+    }
+
+    /// Cache of the downloaded series, if any.
+    pub fn cache(&self) -> Option<&SeriesCache> {
+        self.cache.as_ref()
+    }
+
+    /// Makes an API request to the IADB and deserializes the response into a time series.
+    ///
+    /// Note: If the client has a cache, only the dates after the last cached observation are requested from the IADB,
+    /// and the new observations are merged into the cache.
+    ///
+    /// # Input
+    /// - `series_code`: Code of the time series in the IADB.
+    /// - `date_from`: Date from which the data will be extracted
+    /// - `date_to`: Date up to which the data will be extracted
+    pub async fn get_data(&self, series_code: &SeriesCode, date_from: &NaiveDate, date_to: &NaiveDate) -> Result<IADBSeries, Error> {
+        self.get_data_with_options(series_code, date_from, date_to, &RequestOptions::default()).await
+    }
+
+    /// Makes an API request to the IADB with the provided options and deserializes the response into a time series.
+    ///
+    /// Note: If the client has a cache, the observations are cached with the missing values kept (unless the policy is
+    /// `MissingValuePolicy::Error`), and the missing-value policy and the `metadata` option are applied to the cached series
+    /// when it is returned. Metadata is requested again if the cached series was downloaded without it.
+    ///
+    /// # Input
+    /// - `series_code`: Code of the time series in the IADB.
+    /// - `date_from`: Date from which the data will be extracted
+    /// - `date_to`: Date up to which the data will be extracted
+    /// - `options`: Options of the request (e.g., layout of the CSV response)
+    pub async fn get_data_with_options(&self, series_code: &SeriesCode, date_from: &NaiveDate, date_to: &NaiveDate, options: &RequestOptions) -> Result<IADBSeries, Error> {
+        if date_to < date_from {
+            return Err(Error::InvalidDateRange);
+        }
+        let cache: &SeriesCache = match &self.cache {
+            Some(v) => v,
+            None => return self.fetch_data(series_code, date_from, date_to, options).await,
+        };
+        let _lock = cache.lock(series_code).await;
+        let fetch_options: RequestOptions = cache_options(options);
+        let (mut cached_series, fetch_from, fetch_to) = match cache.load(series_code).await? {
+            // Cached series was downloaded without metadata, so the range is requested again
+            Some(v) if v.date_from <= *date_from && options.metadata && v.series.metadata.is_none() => (v, *date_from, *date_to),
+            Some(v) if v.date_from <= *date_from => {
+                let fetch_from: NaiveDate = v.last_date().and_then(|d| d.checked_add_days(Days::new(1)) ).unwrap_or(v.date_from);
+                (v, fetch_from, *date_to)
+            },
+            // Cached series starts after the requested date, so the range is requested up to the start of the cached series
+            // (at least), so that the cache does not claim the dates between the requested range and the cached series
+            Some(v) => {
+                let fetch_to: NaiveDate = v.date_from.pred_opt().map_or(*date_to, |d| d.max(*date_to) );
+                (v, *date_from, fetch_to)
+            },
+            None => {
+                let series: IADBSeries = IADBSeries { name: series_code.to_string(), description: series_code.description(), data: vec![], metadata: None, };
+                (CachedSeries { date_from: *date_from, series, }, *date_from, *date_to)
+            },
+        };
+        if fetch_from <= fetch_to {
+            match self.fetch_data(series_code, &fetch_from, &fetch_to, &fetch_options).await {
+                Ok(v) => cached_series.series.merge(v),
+                // IADB rejects ranges without any observations, which is expected right after the last cached observation
+                Err(Error::InvalidDateRange) if fetch_from != *date_from => (),
+                Err(e) => return Err(e),
+            }
+            cached_series.date_from = cached_series.date_from.min(*date_from);
+            cache.save(series_code, &cached_series).await?;
+        }
+        apply_options(cached_series.series.between(date_from, date_to), options)
+    }
+
+    /// Requests the series from the IADB regardless of the cached observations, and replaces the cached observations
+    /// in the requested date range with the downloaded ones (e.g., to pick up revised data).
+    ///
+    /// # Input
+    /// - `series_code`: Code of the time series in the IADB.
+    /// - `date_from`: Date from which the data will be extracted
+    /// - `date_to`: Date up to which the data will be extracted
+    pub async fn refresh_data(&self, series_code: &SeriesCode, date_from: &NaiveDate, date_to: &NaiveDate) -> Result<IADBSeries, Error> {
+        self.refresh_data_with_options(series_code, date_from, date_to, &RequestOptions::default()).await
+    }
+
+    /// Requests the series from the IADB with the provided options regardless of the cached observations, and replaces
+    /// the cached observations in the requested date range with the downloaded ones (e.g., to pick up revised data).
+    ///
+    /// Note: If the requested date range does not touch the cached series, the request is widened up to the cached series,
+    /// so that the cache does not claim the dates between them.
+    ///
+    /// # Input
+    /// - `series_code`: Code of the time series in the IADB.
+    /// - `date_from`: Date from which the data will be extracted
+    /// - `date_to`: Date up to which the data will be extracted
+    /// - `options`: Options of the request (e.g., `chunk_days` to split a long date range)
+    pub async fn refresh_data_with_options(&self, series_code: &SeriesCode, date_from: &NaiveDate, date_to: &NaiveDate, options: &RequestOptions) -> Result<IADBSeries, Error> {
+        if date_to < date_from {
+            return Err(Error::InvalidDateRange);
+        }
+        let cache: &SeriesCache = match &self.cache {
+            Some(v) => v,
+            None => return self.fetch_data(series_code, date_from, date_to, options).await,
+        };
+        let _lock = cache.lock(series_code).await;
+        let (mut cached_series, fetch_from, fetch_to) = match cache.load(series_code).await? {
+            Some(v) => {
+                let cached_to: NaiveDate = v.last_date().and_then(|d| d.checked_add_days(Days::new(1)) ).unwrap_or(v.date_from);
+                let fetch_from: NaiveDate = cached_to.min(*date_from);
+                let fetch_to: NaiveDate = v.date_from.pred_opt().map_or(*date_to, |d| d.max(*date_to) );
+                (v, fetch_from, fetch_to)
+            },
+            None => {
+                let series: IADBSeries = IADBSeries { name: series_code.to_string(), description: series_code.description(), data: vec![], metadata: None, };
+                (CachedSeries { date_from: *date_from, series, }, *date_from, *date_to)
+            },
+        };
+        let series: IADBSeries = self.fetch_data(series_code, &fetch_from, &fetch_to, &cache_options(options)).await?;
+        // Observations that were removed from the IADB in the requested date range are removed from the cache as well
+        cached_series.series.data.retain(|v| v.date < fetch_from || fetch_to < v.date );
+        cached_series.series.merge(series.clone());
+        cached_series.date_from = cached_series.date_from.min(fetch_from);
+        cache.save(series_code, &cached_series).await?;
+        apply_options(series.between(date_from, date_to), options)
+    }
+
+    /// Makes an API request to the IADB without using the cache, splitting the date range into windows if the options set `chunk_days`.
+    ///
+    /// Note: The IADB rejects windows without any observations, so such windows are skipped unless all windows are rejected.
+    async fn fetch_data(&self, series_code: &SeriesCode, date_from: &NaiveDate, date_to: &NaiveDate, options: &RequestOptions) -> Result<IADBSeries, Error> {
+        let windows: Vec<(NaiveDate, NaiveDate)> = match options.chunk_days {
+            Some(days) => date_windows(date_from, date_to, days),
+            None => vec![(*date_from, *date_to)],
+        };
+        if windows.len() == 1 {
+            return self.fetch_window(series_code, date_from, date_to, options).await;
+        }
+        let requests: Vec<_> = windows.iter().map(|(window_from, window_to)| self.fetch_window(series_code, window_from, window_to, options) ).collect();
+        let results: Vec<Result<IADBSeries, Error>> = stream::iter(requests).buffered(options.chunk_concurrency.max(1)).collect().await;
+        let mut series: Option<IADBSeries> = None;
+        for result in results {
+            match (result, series.as_mut()) {
+                (Ok(v), Some(series)) => series.merge(v),
+                (Ok(v), None) => series = Some(v),
+                (Err(Error::InvalidDateRange), _) => (),
+                (Err(e), _) => return Err(e),
+            }
+        }
+        series.ok_or(Error::InvalidDateRange)
+    }
+
+    /// Makes a single API request to the IADB for the date range.
+    async fn fetch_window(&self, series_code: &SeriesCode, date_from: &NaiveDate, date_to: &NaiveDate, options: &RequestOptions) -> Result<IADBSeries, Error> {
+        // Parameters
+        let layout: CSVF = options.layout();
+        let using_codes: String = options.using_codes_param();
+        let vfd: String = options.vfd_param();
+        // Request
+        let params: Vec<Param> = vec![
+            Param::DateFrom { v: date_from }, Param::DateTo { v: date_to }, Param::CSVF { v: &layout }, Param::UsingCodes { v: &using_codes },
+            Param::VPD { v: &VPD::Y }, Param::VFD { v: &vfd },
+        ];
+        call_api_endpoint(self, &series_code.to_string(), &Some(series_code.description()), params, &options.missing_values, None).await
+    }
+
+    /// Makes a single API request to the IADB for several time series and deserializes the response into one time series per code.
+    ///
+    /// Note: No request is sent if `series_codes` is empty, and an empty vector is returned.
+    ///
+    /// # Input
+    /// - `series_codes`: Codes of the time series in the IADB.
+    /// - `date_from`: Date from which the data will be extracted
+    /// - `date_to`: Date up to which the data will be extracted
+    pub async fn get_many(&self, series_codes: &[SeriesCode], date_from: &NaiveDate, date_to: &NaiveDate) -> Result<Vec<IADBSeries>, Error> {
+        self.get_many_with_options(series_codes, date_from, date_to, &RequestOptions::default()).await
+    }
+
+    /// Makes a single API request to the IADB for several time series with the provided options
+    /// and deserializes the response into one time series per code.
+    ///
+    /// # Input
+    /// - `series_codes`: Codes of the time series in the IADB.
+    /// - `date_from`: Date from which the data will be extracted
+    /// - `date_to`: Date up to which the data will be extracted
+    /// - `options`: Options of the request (e.g., layout of the CSV response)
+    pub async fn get_many_with_options(&self, series_codes: &[SeriesCode], date_from: &NaiveDate, date_to: &NaiveDate, options: &RequestOptions) -> Result<Vec<IADBSeries>, Error> {
+        if date_to < date_from {
+            return Err(Error::InvalidDateRange);
+        }
+        // Parameters
+        let layout: CSVF = options.layout();
+        let using_codes: String = options.using_codes_param();
+        let vfd: String = options.vfd_param();
+        // Request
+        let params: Vec<Param> = vec![
+            Param::DateFrom { v: date_from }, Param::DateTo { v: date_to }, Param::CSVF { v: &layout }, Param::UsingCodes { v: &using_codes },
+            Param::VPD { v: &VPD::Y }, Param::VFD { v: &vfd },
+        ];
+        call_api_endpoint_many(self, series_codes, params, &options.missing_values, None).await
+    }
+
+    /// Searches the IADB series catalogue and returns the listed series with their descriptions.
+    ///
+    /// Note: This allows finding the codes of the series that are not defined as variants of `SeriesCode`,
+    /// which can then be requested as `SeriesCode::Other`.
+    ///
+    /// # Input
+    /// - `query`: Search terms (e.g., `sonia`)
+    pub async fn search(&self, query: &str) -> Result<Vec<IADBSearchResult>, Error> {
+        call_search_endpoint(self, query).await
+    }
+}
+
+
+/// Options of the requests whose series are cached, which keep the missing observations so that the cache can serve every missing-value policy.
+fn cache_options(options: &RequestOptions) -> RequestOptions {
+    match options.missing_values {
+        MissingValuePolicy::Skip => RequestOptions { missing_values: MissingValuePolicy::KeepAsMissing, ..options.clone() },
+        _ => options.clone(),
+    }
+}
+
+
+/// Applies the missing-value policy and the `metadata` option of the request to a cached series.
+///
+/// Note: The cache does not keep the raw text of the missing values, so `MissingValuePolicy::Error` fails on any missing
+/// observation with an empty `value` in `Error::InvalidValue`.
+fn apply_options(mut series: IADBSeries, options: &RequestOptions) -> Result<IADBSeries, Error> {
+    match options.missing_values {
+        MissingValuePolicy::Error => {
+            if let Some(v) = series.data.iter().find(|v| v.value.is_none() ) {
+                return Err(Error::InvalidValue { series_code: series.name.clone(), date: v.date, value: String::new(), });
+            }
+        },
+        MissingValuePolicy::Skip => series.data.retain(|v| v.value.is_some() ),
+        MissingValuePolicy::KeepAsMissing => (),
+    }
+    if !options.metadata {
+        series.metadata = None;
+    }
+    Ok(series)
+}
+
+
+/// Splits the date range into consecutive windows of at most `days` days (Note: A window size of `0` is treated as `1`).
+fn date_windows(date_from: &NaiveDate, date_to: &NaiveDate, days: u64) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut windows: Vec<(NaiveDate, NaiveDate)> = Vec::new();
+    let mut window_from: NaiveDate = *date_from;
+    while window_from <= *date_to {
+        let window_to: NaiveDate = window_from.checked_add_days(Days::new(days.max(1) - 1)).map_or(*date_to, |v| v.min(*date_to) );
+        windows.push((window_from, window_to));
+        match window_to.checked_add_days(Days::new(1)) {
+            Some(v) => window_from = v,
+            None => break,
+        }
+    }
+    windows
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    #[test]
+    fn unit_test_builder() {
+        use crate::{BASE_URL, client::IADBClient};
+        let client: IADBClient = IADBClient::new().unwrap();
+        assert_eq!(client.base_url(), BASE_URL);
+        let client: IADBClient = IADBClient::builder().base_url("http://127.0.0.1:8080/iadb").timeout(Duration::from_secs(5)).build().unwrap();
+        assert_eq!(client.base_url(), "http://127.0.0.1:8080/iadb");
+    }
+
+    #[tokio::test]
+    async fn unit_test_get_many() {
+        use chrono::NaiveDate;
+        use crate::{SeriesCode, CSVF, RequestOptions, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        server.route("CSVF=CT", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iumbv34_iumbv37_ct.csv"))]);
+        server.route("CSVF=TN", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iumbv34_iumbv37_tn.csv"))]);
+        let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let date_to: NaiveDate = NaiveDate::from_ymd_opt(2000, 3, 31).unwrap();
+        let series_codes: Vec<SeriesCode> = vec![SeriesCode::IUMBV34, SeriesCode::IUMBV37];
+        let data = server.client().get_many(&series_codes, &date_from, &date_to).await.unwrap();
+        assert_eq!(data[1].name, "IUMBV37");
+        assert_eq!(data[1].data[0].value, Some(6.88));
+        let options: RequestOptions = RequestOptions { csv_format: CSVF::CT, ..RequestOptions::default() };
+        let data = server.client().get_many_with_options(&series_codes, &date_from, &date_to, &options).await.unwrap();
+        assert_eq!(data[0].data[2].value, Some(6.67));
+        assert!(server.requests()[0].contains("SeriesCodes=IUMBV34,IUMBV37&Datefrom=01/Jan/2000&Dateto=31/Mar/2000"));
+        // No request is sent without series codes
+        assert!(server.client().get_many(&[], &date_from, &date_to).await.unwrap().is_empty());
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn unit_test_errors_and_retries() {
+        use chrono::NaiveDate;
+        use crate::{SeriesCode, IADBClient, RetryPolicy, error::{Error, ErrorKind}, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        server.route("SeriesCodes=IUDXXXX", vec![MockResponse::html(include_str!("../tests/fixtures/synthetic/unknown_series.html"))]);
+        server.route("SeriesCodes=IUDSOIA", vec![
+            MockResponse::status(503), MockResponse::html(include_str!("../tests/fixtures/synthetic/service_unavailable.html")),
+            MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn.csv")),
+        ]);
+        let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let date_to: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 31).unwrap();
+        let result = server.client().get_data(&SeriesCode::Other(String::from("IUDXXXX")), &date_from, &date_to).await;
+        assert!(matches!(result, Err(Error::UnknownSeries(_))));
+        let result = server.client().get_data(&SeriesCode::IUDSOIA, &date_to, &date_from).await;
+        assert!(matches!(result, Err(Error::InvalidDateRange)));
+        // Codes that would change the query of the URL are rejected before sending the request
+        let result = server.client().get_data(&SeriesCode::Other(String::from("IUDSOIA&VPD=N")), &date_from, &date_to).await;
+        assert!(matches!(result, Err(Error::InvalidSeriesCode(_))));
+        let series_codes: Vec<SeriesCode> = vec![SeriesCode::IUDSOIA, SeriesCode::Other(String::from("IUDSOIA,XUDLUSS"))];
+        let result = server.client().get_many(&series_codes, &date_from, &date_to).await;
+        assert!(matches!(result, Err(Error::InvalidSeriesCode(_))));
+        // Maintenance page is not retried by default
+        let client: IADBClient = IADBClient::builder().base_url(&server.url())
+            .retry_policy(RetryPolicy::default().initial_backoff(Duration::from_millis(10))).build().unwrap();
+        let result = client.get_data(&SeriesCode::IUDSOIA, &date_from, &date_to).await;
+        assert!(matches!(result, Err(Error::UnexpectedResponse { .. })));
+        assert_eq!(server.requests().len(), 3);
+        // Status code and the maintenance page are retried when opted in, after which the CSV data is served
+        let server: MockServer = MockServer::start().await;
+        server.route("SeriesCodes=IUDSOIA", vec![
+            MockResponse::status(503), MockResponse::html(include_str!("../tests/fixtures/synthetic/service_unavailable.html")),
+            MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn.csv")),
+        ]);
+        let retryable: Vec<ErrorKind> = vec![ErrorKind::ServerError, ErrorKind::UnexpectedResponse];
+        let client: IADBClient = IADBClient::builder().base_url(&server.url())
+            .retry_policy(RetryPolicy::default().initial_backoff(Duration::from_millis(10)).retryable(retryable)).build().unwrap();
+        let data = client.get_data(&SeriesCode::IUDSOIA, &date_from, &date_to).await.unwrap();
+        assert_eq!(data.data.len(), 5);
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn unit_test_cached_get_data() {
+        use chrono::NaiveDate;
+        use crate::{SeriesCode, IADBClient, cache::SeriesCache, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        server.route("Datefrom=11/Jan/2000", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn_update.csv"))]);
+        server.route("SeriesCodes=IUDSOIA", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn.csv"))]);
+        let cache: SeriesCache = SeriesCache::new(std::env::temp_dir().join(format!("iadb-api-client-cache-{}", std::process::id())));
+        let client: IADBClient = IADBClient::builder().base_url(&server.url()).cache(cache.clone()).build().unwrap();
+        let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let date_to: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 10).unwrap();
+        assert_eq!(client.get_data(&SeriesCode::IUDSOIA, &date_from, &date_to).await.unwrap().data.len(), 5);
+        // Cached range is served without a request, and a later range only requests the dates after the last observation
+        assert_eq!(client.get_data(&SeriesCode::IUDSOIA, &date_from, &date_to).await.unwrap().data.len(), 5);
+        assert_eq!(server.requests().len(), 1);
+        let date_to: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 12).unwrap();
+        assert_eq!(client.get_data(&SeriesCode::IUDSOIA, &date_from, &date_to).await.unwrap().data.len(), 7);
+        assert!(server.requests()[1].contains("Datefrom=11/Jan/2000&Dateto=12/Jan/2000"));
+        // Forced refresh requests the whole range again
+        assert_eq!(client.refresh_data(&SeriesCode::IUDSOIA, &date_from, &date_to).await.unwrap().data.len(), 5);
+        assert!(server.requests()[2].contains("Datefrom=01/Jan/2000&Dateto=12/Jan/2000"));
+        std::fs::remove_dir_all(cache.directory()).unwrap();
+        // Earlier range than the cached series is requested up to the start of the cached series, so that no dates are missed later
+        let cache: SeriesCache = SeriesCache::new(std::env::temp_dir().join(format!("iadb-api-client-cache-gap-{}", std::process::id())));
+        let client: IADBClient = IADBClient::builder().base_url(&server.url()).cache(cache.clone()).build().unwrap();
+        let d = |day: u32| NaiveDate::from_ymd_opt(2000, 1, day).unwrap();
+        assert_eq!(client.get_data(&SeriesCode::IUDSOIA, &d(11), &d(12)).await.unwrap().data.len(), 2);
+        assert_eq!(client.get_data(&SeriesCode::IUDSOIA, &d(1), &d(4)).await.unwrap().data.len(), 1);
+        assert!(server.requests()[4].contains("Datefrom=01/Jan/2000&Dateto=10/Jan/2000"));
+        let dates: Vec<NaiveDate> = client.get_data(&SeriesCode::IUDSOIA, &d(1), &d(12)).await.unwrap().data.iter().map(|v| v.date ).collect();
+        assert_eq!(dates, vec![d(4), d(5), d(6), d(7), d(10), d(11), d(12)]);
+        assert_eq!(server.requests().len(), 5);
+        std::fs::remove_dir_all(cache.directory()).unwrap();
+        // Refresh of a later range than the cached series is requested from the end of the cached series, so that no dates are missed later
+        let cache: SeriesCache = SeriesCache::new(std::env::temp_dir().join(format!("iadb-api-client-cache-refresh-{}", std::process::id())));
+        let client: IADBClient = IADBClient::builder().base_url(&server.url()).cache(cache.clone()).build().unwrap();
+        assert_eq!(client.get_data(&SeriesCode::IUDSOIA, &d(1), &d(10)).await.unwrap().data.len(), 5);
+        assert!(client.refresh_data(&SeriesCode::IUDSOIA, &d(20), &d(25)).await.unwrap().data.is_empty());
+        assert!(server.requests()[6].contains("Datefrom=11/Jan/2000&Dateto=25/Jan/2000"));
+        assert_eq!(client.get_data(&SeriesCode::IUDSOIA, &d(1), &d(12)).await.unwrap().data.len(), 7);
+        assert_eq!(server.requests().len(), 7);
+        std::fs::remove_dir_all(cache.directory()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn unit_test_cached_options() {
+        use chrono::NaiveDate;
+        use crate::{SeriesCode, IADBClient, MissingValuePolicy, RequestOptions, error::Error, cache::SeriesCache, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        let body: &str = "DATE,IUDSOIA\n04 Jan 2000,5.2664\n05 Jan 2000,\n06 Jan 2000,5.2279\n";
+        server.route("CSVF=TT", vec![MockResponse::csv(&format!("SERIES,DESCRIPTION,UNITS\nIUDSOIA,Daily SONIA rate,Percent\n\n{}", body))]);
+        server.route("SeriesCodes=IUDSOIA", vec![MockResponse::csv(body)]);
+        let cache: SeriesCache = SeriesCache::new(std::env::temp_dir().join(format!("iadb-api-client-cache-options-{}", std::process::id())));
+        let client: IADBClient = IADBClient::builder().base_url(&server.url()).cache(cache.clone()).build().unwrap();
+        let d = |day: u32| NaiveDate::from_ymd_opt(2000, 1, day).unwrap();
+        // Missing observation is cached, but only returned to the requests that keep missing values
+        assert_eq!(client.get_data(&SeriesCode::IUDSOIA, &d(4), &d(6)).await.unwrap().data.len(), 2);
+        let options: RequestOptions = RequestOptions { missing_values: MissingValuePolicy::KeepAsMissing, ..RequestOptions::default() };
+        let data = client.get_data_with_options(&SeriesCode::IUDSOIA, &d(4), &d(6), &options).await.unwrap();
+        assert_eq!(data.data[1].value, None);
+        assert_eq!(server.requests().len(), 1);
+        // Missing observation in the cache fails the requests that do not accept missing values
+        let options: RequestOptions = RequestOptions { missing_values: MissingValuePolicy::Error, ..RequestOptions::default() };
+        let result = client.get_data_with_options(&SeriesCode::IUDSOIA, &d(4), &d(6), &options).await;
+        assert!(matches!(result, Err(Error::InvalidValue { date, .. }) if date == d(5)));
+        assert_eq!(client.get_data_with_options(&SeriesCode::IUDSOIA, &d(6), &d(6), &options).await.unwrap().data.len(), 1);
+        assert_eq!(server.requests().len(), 1);
+        // Metadata is requested again if the cached series has none, and is only returned if requested
+        let options: RequestOptions = RequestOptions { metadata: true, ..RequestOptions::default() };
+        let data = client.get_data_with_options(&SeriesCode::IUDSOIA, &d(4), &d(6), &options).await.unwrap();
+        assert_eq!(data.metadata.unwrap().units.as_deref(), Some("Percent"));
+        assert_eq!(data.data.len(), 2);
+        assert!(client.get_data(&SeriesCode::IUDSOIA, &d(4), &d(6)).await.unwrap().metadata.is_none());
+        assert_eq!(server.requests().len(), 2);
+        std::fs::remove_dir_all(cache.directory()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn unit_test_chunked_get_data() {
+        use chrono::NaiveDate;
+        use crate::{SeriesCode, IADBClient, RequestOptions, cache::SeriesCache, client::date_windows, mock::{MockServer, MockResponse}};
+        let d = |day: u32| NaiveDate::from_ymd_opt(2000, 1, day).unwrap();
+        assert_eq!(date_windows(&d(1), &d(12), 5), vec![(d(1), d(5)), (d(6), d(10)), (d(11), d(12))]);
+        assert_eq!(date_windows(&d(1), &d(1), 5), vec![(d(1), d(1))]);
+        let server: MockServer = MockServer::start().await;
+        server.route("Datefrom=16/Jan/2000", vec![MockResponse::html("<html><body><p>No data for the date range</p></body></html>")]);
+        server.route("Datefrom=11/Jan/2000", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn_update.csv"))]);
+        server.route("SeriesCodes=IUDSOIA", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsoia_tn.csv"))]);
+        let options: RequestOptions = RequestOptions { chunk_days: Some(5), chunk_concurrency: 2, ..RequestOptions::default() };
+        let data = server.client().get_data_with_options(&SeriesCode::IUDSOIA, &d(1), &d(20), &options).await.unwrap();
+        // Overlapping observations are de-duplicated, and the window without observations is skipped
+        let dates: Vec<NaiveDate> = data.data.iter().map(|v| v.date ).collect();
+        assert_eq!(dates, vec![d(4), d(5), d(6), d(7), d(10), d(11), d(12)]);
+        assert_eq!(server.requests().len(), 4);
+        assert!(server.requests().iter().any(|v| v.contains("Datefrom=06/Jan/2000&Dateto=10/Jan/2000") ));
+        // Forced refresh of a cached series is chunked as well
+        let cache: SeriesCache = SeriesCache::new(std::env::temp_dir().join(format!("iadb-api-client-cache-chunks-{}", std::process::id())));
+        let client: IADBClient = IADBClient::builder().base_url(&server.url()).cache(cache.clone()).build().unwrap();
+        let data = client.refresh_data_with_options(&SeriesCode::IUDSOIA, &d(1), &d(20), &options).await.unwrap();
+        assert_eq!(data.data.len(), 7);
+        assert_eq!(server.requests().len(), 8);
+        assert_eq!(cache.load(&SeriesCode::IUDSOIA).await.unwrap().unwrap().series.data.len(), 7);
+        std::fs::remove_dir_all(cache.directory()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn unit_test_search() {
+        use crate::{SeriesCode, IADBSearchResult, catalog::Frequency, error::Error, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        server.route("SearchText=sonia", vec![MockResponse::html(include_str!("../tests/fixtures/synthetic/search_sonia.html"))]);
+        server.route("SearchText=xyzzy", vec![MockResponse::html(include_str!("../tests/fixtures/synthetic/search_no_results.html"))]);
+        let results: Vec<IADBSearchResult> = server.client().search("sonia").await.unwrap();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[2].series_code, SeriesCode::Other(String::from("IUDZOS2")));
+        assert_eq!(results[2].frequency, Some(Frequency::Daily));
+        assert!(server.client().search("xyzzy").await.unwrap().is_empty());
+        // Page that neither lists series nor says that nothing was found is not taken as an empty result
+        server.route("SearchText=gilts", vec![MockResponse::html(include_str!("../tests/fixtures/synthetic/service_unavailable.html"))]);
+        assert!(matches!(server.client().search("gilts").await, Err(Error::UnexpectedResponse { .. })));
+        // Search terms are URL-encoded, and failed requests are reported with the status code
+        assert!(matches!(server.client().search("gilt yields").await, Err(Error::HttpStatus(404))));
+        assert!(server.requests()[3].ends_with("?SearchText=gilt+yields"));
+    }
+}
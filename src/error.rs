@@ -1,7 +1,13 @@
 // Error types
 use std::error::Error as ErrorTrait;
+use std::num::ParseFloatError;
 use reqwest::Error as ReqwestError;
 use csv::Error as CSVError;
+use chrono::{NaiveDate, ParseError as ChronoParseError};
+use std::io::Error as IOError;
+use serde_json::Error as JSONError;
+#[cfg(feature = "arrow")]
+use arrow_schema::ArrowError;
 // Dependencies
 use std::{fmt::Display, convert::From};
 
@@ -10,23 +16,119 @@ use std::{fmt::Display, convert::From};
 pub enum Error {
     // Reqwest errors
     ReqwestError(ReqwestError),
+    // Transport errors
+    TransportError(String),
     // CSV errors
     CSVError(CSVError),
+    // Parsing errors
+    ParseFloatError(ParseFloatError),
+    ParseDateError(ChronoParseError),
+    InvalidValue { series_code: String, date: NaiveDate, value: String },
+    // Cache errors
+    IOError(IOError),
+    JSONError(JSONError),
+    // Arrow errors
+    #[cfg(feature = "arrow")]
+    ArrowError(ArrowError),
+    // Request errors
+    InvalidDateRange,
+    InvalidSeriesCode(String),
+    // Analytics errors
+    InvalidCurve(String),
+    MissingObservation { series_code: String, date: NaiveDate },
+    UnknownCurrency(String),
+    InvalidCurrencyPair(String),
+    // Response errors
+    HttpStatus(u16),
+    UnknownSeries(String),
+    UnexpectedResponse { snippet: String },
+    MissingColumn { series_code: String },
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             // Reqwest errors
-            Error::ReqwestError(e) => write!(f, "Reqwest Error: {}", e.to_string()),
+            Error::ReqwestError(e) => write!(f, "Reqwest Error: {}", e),
+            // Transport errors
+            Error::TransportError(e) => write!(f, "Transport Error: {}", e),
             // CSV errors
-            Error::CSVError(e) => write!(f, "CSV Error: {}", e.to_string()),
+            Error::CSVError(e) => write!(f, "CSV Error: {}", e),
+            // Parsing errors
+            Error::ParseFloatError(e) => write!(f, "Parse Float Error: {}", e),
+            Error::ParseDateError(e) => write!(f, "Parse Date Error: {}", e),
+            Error::InvalidValue { series_code, date, value } => write!(f, "Invalid Value: The series `{}` has a non-numeric value `{}` on {}", series_code, value, date),
+            // Cache errors
+            Error::IOError(e) => write!(f, "IO Error: {}", e),
+            Error::JSONError(e) => write!(f, "JSON Error: {}", e),
+            // Arrow errors
+            #[cfg(feature = "arrow")]
+            Error::ArrowError(e) => write!(f, "Arrow Error: {}", e),
+            // Request errors
+            Error::InvalidDateRange => write!(f, "Invalid Date Range: The requested date range is not valid"),
+            Error::InvalidSeriesCode(series_code) => write!(f, "Invalid Series Code: `{}` is not a valid IADB series code", series_code),
+            // Analytics errors
+            Error::InvalidCurve(reason) => write!(f, "Invalid Curve: {}", reason),
+            Error::MissingObservation { series_code, date } => write!(f, "Missing Observation: The series `{}` has no observation on or before {}", series_code, date),
+            Error::UnknownCurrency(currency) => write!(f, "Unknown Currency: No sterling spot rate series for the currency `{}`", currency),
+            Error::InvalidCurrencyPair(pair) => write!(f, "Invalid Currency Pair: `{}` is not a valid currency pair", pair),
+            // Response errors
+            Error::HttpStatus(status) => write!(f, "HTTP Status: The IADB responded with status code {}", status),
+            Error::UnknownSeries(series_codes) => write!(f, "Unknown Series: The IADB does not recognise the series `{}`", series_codes),
+            Error::UnexpectedResponse { snippet } => write!(f, "Unexpected Response: The IADB did not respond with CSV data: {}", snippet),
+            Error::MissingColumn { series_code } => write!(f, "Missing Column: No column for the series `{}` in the response", series_code),
         }
     }
 }
 
 impl ErrorTrait for Error {}
 
+impl Error {
+    /// Category of the error, used to decide whether a failed request can be retried.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::ReqwestError(e) if e.is_timeout() => ErrorKind::Timeout,
+            Error::ReqwestError(e) if e.is_builder() => ErrorKind::InvalidRequest,
+            Error::ReqwestError(_) | Error::TransportError(_) => ErrorKind::Connection,
+            Error::CSVError(_) | Error::ParseFloatError(_) | Error::ParseDateError(_) | Error::InvalidValue { .. }
+            | Error::MissingColumn { .. } => ErrorKind::Parse,
+            Error::IOError(_) | Error::JSONError(_) => ErrorKind::Cache,
+            #[cfg(feature = "arrow")]
+            Error::ArrowError(_) => ErrorKind::Parse,
+            Error::InvalidDateRange | Error::InvalidSeriesCode(_) | Error::UnknownSeries(_) | Error::InvalidCurve(_)
+            | Error::MissingObservation { .. } | Error::UnknownCurrency(_) | Error::InvalidCurrencyPair(_) => ErrorKind::InvalidRequest,
+            Error::HttpStatus(429) => ErrorKind::Throttled,
+            Error::HttpStatus(status) if 500 <= *status => ErrorKind::ServerError,
+            Error::HttpStatus(_) => ErrorKind::ClientError,
+            Error::UnexpectedResponse { .. } => ErrorKind::UnexpectedResponse,
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Category of an `Error`.
+pub enum ErrorKind {
+    /// Connection could not be established or was reset.
+    Connection,
+    /// Request timed out.
+    Timeout,
+    /// IADB responded with status code `429`.
+    Throttled,
+    /// IADB responded with a `5xx` status code.
+    ServerError,
+    /// IADB responded with a `4xx` status code other than `429`.
+    ClientError,
+    /// IADB responded with a page that is neither CSV data nor a known error message.
+    UnexpectedResponse,
+    /// Request was rejected because of its parameters.
+    InvalidRequest,
+    /// Response could not be deserialized.
+    Parse,
+    /// Cached series could not be read or written.
+    Cache,
+}
+
 impl From<ReqwestError> for Error {
     fn from(value: ReqwestError) -> Self {
         Error::ReqwestError(value)
@@ -37,4 +139,35 @@ impl From<CSVError> for Error {
     fn from(value: CSVError) -> Self {
         Error::CSVError(value)
     }
-}
\ No newline at end of file
+}
+
+impl From<ParseFloatError> for Error {
+    fn from(value: ParseFloatError) -> Self {
+        Error::ParseFloatError(value)
+    }
+}
+
+impl From<ChronoParseError> for Error {
+    fn from(value: ChronoParseError) -> Self {
+        Error::ParseDateError(value)
+    }
+}
+
+impl From<IOError> for Error {
+    fn from(value: IOError) -> Self {
+        Error::IOError(value)
+    }
+}
+
+impl From<JSONError> for Error {
+    fn from(value: JSONError) -> Self {
+        Error::JSONError(value)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<ArrowError> for Error {
+    fn from(value: ArrowError) -> Self {
+        Error::ArrowError(value)
+    }
+}
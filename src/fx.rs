@@ -0,0 +1,256 @@
+use std::{fmt, str::FromStr, collections::BTreeMap};
+use serde::{Serialize, Deserialize};
+use chrono::NaiveDate;
+use crate::error::Error;
+use crate::{SeriesCode, client::IADBClient, schemas::{IADBSeries, IADBDataPoint}};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+/// Currency with a sterling spot exchange rate series in the IADB (Note: The currencies are (de)serialized as ISO 4217 codes).
+pub enum Currency {
+    /// Pound sterling
+    GBP,
+    /// US dollar (`XUDLUSS`)
+    USD,
+    /// Euro (`XUDLERS`)
+    EUR,
+    /// Japanese yen (`XUDLJYS`)
+    JPY,
+    /// Canadian dollar (`XUDLCDS`)
+    CAD,
+    /// Swiss franc (`XUDLSFS`)
+    CHF,
+    /// Danish krone (`XUDLDKS`)
+    DKK,
+    /// Norwegian krone (`XUDLNKS`)
+    NOK,
+    /// Swedish krona (`XUDLSKS`)
+    SEK,
+    /// Singapore dollar (`XUDLSGS`)
+    SGD,
+}
+
+impl Currency {
+
+    /// Iterates over all currencies.
+    pub fn all() -> impl Iterator<Item = Currency> {
+        [
+            Currency::GBP, Currency::USD, Currency::EUR, Currency::JPY, Currency::CAD, Currency::CHF, Currency::DKK, Currency::NOK,
+            Currency::SEK, Currency::SGD,
+        ].into_iter()
+    }
+
+    /// Series code of the spot exchange rate of the currency into sterling (i.e., units of the currency per £1),
+    /// or `None` for sterling itself.
+    pub fn series_code(&self) -> Option<SeriesCode> {
+        match self {
+            Currency::GBP => None,
+            Currency::USD => Some(SeriesCode::XUDLUSS),
+            Currency::EUR => Some(SeriesCode::XUDLERS),
+            Currency::JPY => Some(SeriesCode::XUDLJYS),
+            Currency::CAD => Some(SeriesCode::XUDLCDS),
+            Currency::CHF => Some(SeriesCode::XUDLSFS),
+            Currency::DKK => Some(SeriesCode::XUDLDKS),
+            Currency::NOK => Some(SeriesCode::XUDLNKS),
+            Currency::SEK => Some(SeriesCode::XUDLSKS),
+            Currency::SGD => Some(SeriesCode::XUDLSGS),
+        }
+    }
+
+    /// Name of the currency.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Currency::GBP => "Pound sterling",
+            Currency::USD => "US dollar",
+            Currency::EUR => "Euro",
+            Currency::JPY => "Japanese yen",
+            Currency::CAD => "Canadian dollar",
+            Currency::CHF => "Swiss franc",
+            Currency::DKK => "Danish krone",
+            Currency::NOK => "Norwegian krone",
+            Currency::SEK => "Swedish krona",
+            Currency::SGD => "Singapore dollar",
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for Currency {
+    type Err = Error;
+
+    /// Parses the ISO 4217 code of the currency (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Currency::all().find(|v| v.to_string().eq_ignore_ascii_case(s.trim()) ).ok_or(Error::UnknownCurrency(String::from(s)))
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Currency pair whose rate is the number of units of the `quote` currency per one unit of the `base` currency (e.g., `EUR/USD`).
+pub struct CurrencyPair {
+    pub base: Currency,
+    pub quote: Currency,
+}
+
+impl CurrencyPair {
+
+    /// Creates a currency pair, which must consist of two different currencies.
+    pub fn new(base: Currency, quote: Currency) -> Result<Self, Error> {
+        if base == quote {
+            return Err(Error::InvalidCurrencyPair(format!("{}/{}", base, quote)));
+        }
+        Ok(CurrencyPair { base, quote, })
+    }
+
+    /// Series codes of the sterling spot rates that the rate of the pair is derived from.
+    pub fn legs(&self) -> Vec<SeriesCode> {
+        [self.base, self.quote].iter().filter_map(|v| v.series_code() ).collect()
+    }
+
+    /// Derives the rate of the pair from the sterling spot rates of its legs, keeping only the dates on which all legs have an observation.
+    ///
+    /// Note: The rate is triangulated through sterling, i.e., `BASE/QUOTE = (QUOTE per £1) / (BASE per £1)`.
+    ///
+    /// # Input
+    /// - `legs`: Sterling spot rate series of the pair (i.e., the series of `legs`), in any order
+    pub fn from_legs(&self, legs: &[IADBSeries]) -> Result<IADBSeries, Error> {
+        let base: Option<BTreeMap<NaiveDate, f64>> = self.base.series_code().map(|v| leg_rates(&v, legs) ).transpose()?;
+        let quote: Option<BTreeMap<NaiveDate, f64>> = self.quote.series_code().map(|v| leg_rates(&v, legs) ).transpose()?;
+        let data: Vec<IADBDataPoint> = match (base, quote) {
+            (Some(base), Some(quote)) => base.iter()
+                .filter_map(|(date, b)| quote.get(date).map(|q| IADBDataPoint { date: *date, value: Some(q / b), } ) )
+                .collect(),
+            (Some(base), None) => base.iter().map(|(date, b)| IADBDataPoint { date: *date, value: Some(1.0 / b), } ).collect(),
+            (None, Some(quote)) => quote.iter().map(|(date, q)| IADBDataPoint { date: *date, value: Some(*q), } ).collect(),
+            (None, None) => return Err(Error::InvalidCurrencyPair(self.to_string())),
+        };
+        let legs: Vec<String> = self.legs().iter().map(|v| v.to_string() ).collect();
+        let description: String = format!(
+            "Spot exchange rate, {} per {} (derived from {})", self.quote.name(), self.base.name(), legs.join(" and "),
+        );
+        Ok(IADBSeries { name: self.to_string(), description, data, metadata: None, })
+    }
+}
+
+impl fmt::Display for CurrencyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.base, self.quote)
+    }
+}
+
+impl FromStr for CurrencyPair {
+    type Err = Error;
+
+    /// Parses the currency pair from ISO 4217 codes, with or without a separator (e.g., `EUR/USD` or `EURUSD`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let codes: String = s.trim().chars().filter(|c| c.is_ascii_alphabetic() ).collect();
+        if codes.len() != 6 {
+            return Err(Error::InvalidCurrencyPair(String::from(s)));
+        }
+        CurrencyPair::new(codes[..3].parse()?, codes[3..].parse()?)
+    }
+}
+
+
+/// Non-missing observations of the leg, keyed by date.
+fn leg_rates(series_code: &SeriesCode, legs: &[IADBSeries]) -> Result<BTreeMap<NaiveDate, f64>, Error> {
+    let series_code: String = series_code.to_string();
+    let leg: &IADBSeries = legs.iter().find(|v| v.name == series_code ).ok_or(Error::MissingColumn { series_code, })?;
+    Ok(leg.data.iter().filter_map(|v| v.value.map(|value| (v.date, value) ) ).collect())
+}
+
+
+/// Fetches the sterling spot rates of the legs from the IADB and derives the cross rate of the currency pair.
+///
+/// # Input
+/// - `client`: Client that sends the request
+/// - `pair`: Currency pair
+/// - `date_from`: Date from which the data will be extracted
+/// - `date_to`: Date up to which the data will be extracted
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use iadb_api::{NaiveDate, IADBClient, IADBSeries, fx::{self, Currency, CurrencyPair}};
+///
+/// #[tokio::main]
+/// async fn main() -> () {
+///
+///     // Parameters
+///     let client: IADBClient = IADBClient::new().unwrap();
+///     let pair: CurrencyPair = CurrencyPair::new(Currency::EUR, Currency::USD).unwrap();
+///     let date_from: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+///     let date_to: NaiveDate = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+///
+///     // Data collection
+///     let data: IADBSeries = fx::cross_rate(&client, &pair, &date_from, &date_to).await.unwrap();
+///
+///     println!("{}", data);
+///
+/// }
+/// ```
+pub async fn cross_rate(client: &IADBClient, pair: &CurrencyPair, date_from: &NaiveDate, date_to: &NaiveDate) -> Result<IADBSeries, Error> {
+    cross_rates(client, std::slice::from_ref(pair), date_from, date_to).await.map(|mut v| v.remove(0) )
+}
+
+
+/// Fetches the sterling spot rates of all legs from the IADB in a single request and derives the cross rates of the currency pairs.
+///
+/// # Input
+/// - `client`: Client that sends the request
+/// - `pairs`: Currency pairs
+/// - `date_from`: Date from which the data will be extracted
+/// - `date_to`: Date up to which the data will be extracted
+pub async fn cross_rates(client: &IADBClient, pairs: &[CurrencyPair], date_from: &NaiveDate, date_to: &NaiveDate) -> Result<Vec<IADBSeries>, Error> {
+    let mut series_codes: Vec<SeriesCode> = Vec::new();
+    for series_code in pairs.iter().flat_map(|v| v.legs() ) {
+        if !series_codes.contains(&series_code) {
+            series_codes.push(series_code);
+        }
+    }
+    let legs: Vec<IADBSeries> = client.get_many(&series_codes, date_from, date_to).await?;
+    pairs.iter().map(|v| v.from_legs(&legs) ).collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_currency_pair() {
+        use crate::{SeriesCode, fx::{Currency, CurrencyPair}};
+        let pair: CurrencyPair = "eur/usd".parse().unwrap();
+        assert_eq!(pair, CurrencyPair::new(Currency::EUR, Currency::USD).unwrap());
+        assert_eq!(pair.to_string(), "EUR/USD");
+        assert_eq!(pair.legs(), vec![SeriesCode::XUDLERS, SeriesCode::XUDLUSS]);
+        assert_eq!("GBPJPY".parse::<CurrencyPair>().unwrap().legs(), vec![SeriesCode::XUDLJYS]);
+        assert!("USD/USD".parse::<CurrencyPair>().is_err());
+        assert!("EUR/XYZ".parse::<CurrencyPair>().is_err());
+        assert!(Currency::all().filter(|v| *v != Currency::GBP ).all(|v| v.series_code().and_then(|s| s.units() ).is_some() ));
+    }
+
+    #[tokio::test]
+    async fn unit_test_cross_rates() {
+        use chrono::NaiveDate;
+        use crate::{IADBSeries, fx::{self, CurrencyPair}, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        server.route("SeriesCodes=XUDLERS,XUDLUSS&", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/xudlers_xudluss_tn.csv"))]);
+        let date_from: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let date_to: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let pairs: Vec<CurrencyPair> = vec!["EUR/USD".parse().unwrap(), "USD/GBP".parse().unwrap(), "GBP/EUR".parse().unwrap()];
+        let data: Vec<IADBSeries> = fx::cross_rates(&server.client(), &pairs, &date_from, &date_to).await.unwrap();
+        assert_eq!(server.requests().len(), 1);
+        // Dates on which a leg has no observation are dropped
+        assert_eq!(data[0].name, "EUR/USD");
+        assert_eq!(data[0].data.len(), 3);
+        assert!((data[0].data[0].value.unwrap() - 1.2699 / 1.1535).abs() < 1e-12);
+        assert_eq!(data[1].data.len(), 4);
+        assert!((data[1].data[2].value.unwrap() - 1.0 / 1.2672).abs() < 1e-12);
+        assert_eq!(data[2].data[2].value, Some(1.1583));
+    }
+}
@@ -0,0 +1,144 @@
+//! Local stand-in for the IADB that serves canned responses, so that the fetch-and-parse path can be tested offline.
+use std::sync::{Arc, Mutex};
+use std::net::SocketAddr;
+use tokio::{io::{AsyncReadExt, AsyncWriteExt}, net::{TcpListener, TcpStream}, task::JoinHandle};
+use crate::{client::IADBClient, retry::RetryPolicy};
+
+
+/// Path of the IADB endpoint on the mock server.
+const ENDPOINT_PATH: &str = "/boeapps/iadb/fromshowcolumns.asp";
+
+/// Path of the IADB series search page on the mock server.
+const SEARCH_PATH: &str = "/boeapps/database/SearchResults.asp";
+
+
+/// Canned response of the IADB.
+#[derive(Debug, Clone)]
+pub(crate) struct MockResponse {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+}
+
+impl MockResponse {
+
+    /// CSV response with status code `200`.
+    pub fn csv(body: &str) -> Self {
+        MockResponse { status: 200, content_type: "text/csv", body: String::from(body), }
+    }
+
+    /// HTML response with status code `200`, which is how the IADB reports invalid requests.
+    pub fn html(body: &str) -> Self {
+        MockResponse { status: 200, content_type: "text/html; charset=utf-8", body: String::from(body), }
+    }
+
+    /// Empty response with the provided status code.
+    pub fn status(status: u16) -> Self {
+        MockResponse { status, content_type: "text/plain", body: String::new(), }
+    }
+}
+
+
+/// Route of the mock server that serves its responses in order, repeating the last one.
+struct MockRoute {
+    pattern: String,
+    responses: Vec<MockResponse>,
+    served: usize,
+}
+
+
+/// HTTP server on a local port that serves canned IADB responses.
+///
+/// Note: Requests are matched to the first route whose pattern is contained in the request target (path and query),
+/// and requests that do not match any route are answered with status code `404`.
+pub(crate) struct MockServer {
+    address: SocketAddr,
+    routes: Arc<Mutex<Vec<MockRoute>>>,
+    requests: Arc<Mutex<Vec<String>>>,
+    handle: JoinHandle<()>,
+}
+
+impl MockServer {
+
+    /// Starts the server on a free local port.
+    pub async fn start() -> Self {
+        let listener: TcpListener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address: SocketAddr = listener.local_addr().unwrap();
+        let routes: Arc<Mutex<Vec<MockRoute>>> = Arc::new(Mutex::new(Vec::new()));
+        let requests: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+        let (server_routes, server_requests) = (routes.clone(), requests.clone());
+        let handle: JoinHandle<()> = tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let (routes, requests) = (server_routes.clone(), server_requests.clone());
+                tokio::spawn(async move { let _ = MockServer::handle(stream, routes, requests).await; });
+            }
+        });
+        MockServer { address, routes, requests, handle, }
+    }
+
+    /// Adds a route that serves the responses in order, repeating the last one.
+    pub fn route(&self, pattern: &str, responses: Vec<MockResponse>) -> &Self {
+        self.routes.lock().unwrap().push(MockRoute { pattern: String::from(pattern), responses, served: 0, });
+        self
+    }
+
+    /// URL of the IADB endpoint on the server.
+    pub fn url(&self) -> String {
+        format!("http://{}{}", self.address, ENDPOINT_PATH)
+    }
+
+    /// URL of the IADB series search page on the server.
+    pub fn search_url(&self) -> String {
+        format!("http://{}{}", self.address, SEARCH_PATH)
+    }
+
+    /// Client of the server that does not retry failed requests.
+    pub fn client(&self) -> IADBClient {
+        IADBClient::builder().base_url(&self.url()).search_url(&self.search_url()).retry_policy(RetryPolicy::none()).build().unwrap()
+    }
+
+    /// Targets (path and query) of the requests received so far.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Reads a request from the stream and writes the response of the matching route.
+    async fn handle(mut stream: TcpStream, routes: Arc<Mutex<Vec<MockRoute>>>, requests: Arc<Mutex<Vec<String>>>) -> std::io::Result<()> {
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut chunk: [u8; 1024] = [0; 1024];
+        while !buffer.windows(4).any(|w| w == b"\r\n\r\n" ) {
+            let n: usize = stream.read(&mut chunk).await?;
+            if n == 0 {
+                break;
+            }
+            buffer.extend_from_slice(&chunk[..n]);
+        }
+        let request: String = String::from_utf8_lossy(&buffer).into_owned();
+        let target: String = request.split_whitespace().nth(1).unwrap_or("").to_string();
+        requests.lock().unwrap().push(target.clone());
+        let response: MockResponse = {
+            let mut routes = routes.lock().unwrap();
+            match routes.iter_mut().find(|r| target.contains(&r.pattern) ) {
+                Some(route) => {
+                    let response: MockResponse = route.responses[route.served.min(route.responses.len() - 1)].clone();
+                    route.served += 1;
+                    response
+                },
+                None => MockResponse::status(404),
+            }
+        };
+        let head: String = format!(
+            "HTTP/1.1 {} Mock\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            response.status, response.content_type, response.body.len(),
+        );
+        stream.write_all(head.as_bytes()).await?;
+        stream.write_all(response.body.as_bytes()).await?;
+        stream.shutdown().await
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
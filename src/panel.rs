@@ -0,0 +1,238 @@
+use std::{fmt, collections::{BTreeSet, HashMap}};
+use serde::{Serialize, Deserialize};
+use chrono::NaiveDate;
+use crate::{SeriesCode, schemas::{IADBSeries, IADBDataPoint}};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Method of matching the dates of the series in a panel.
+pub enum JoinType {
+    /// Dates that are present in all series.
+    Inner,
+    /// Dates that are present in any series, with missing values for the series without an observation on the date.
+    Outer,
+    /// Dates of the first series, with the latest non-missing observation of every other series on or before the date
+    /// (e.g., the latest monthly rate for every day of a daily series).
+    AsOf {
+        /// Maximum number of days between the date and the matched observation, beyond which the value is missing.
+        tolerance: Option<u64>,
+    },
+}
+
+
+/// Column of a panel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PanelColumn {
+    name: String,
+    values: Vec<Option<f64>>,
+}
+
+
+/// Several series joined on date into a table with one row per date and one column per series.
+///
+/// # Examples
+///
+/// ```rust
+/// use iadb_api::{NaiveDate, SeriesCode, IADBSeries, IADBDataPoint, panel::{IADBPanel, JoinType}};
+///
+/// let d = |month: u32, day: u32| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+/// let point = |date: NaiveDate, value: f64| IADBDataPoint { date, value: Some(value) };
+/// let daily: IADBSeries = IADBSeries {
+///     name: String::from("IUDSOIA"), description: String::new(), data: vec![point(d(1, 31), 5.19), point(d(2, 1), 5.2)], metadata: None,
+/// };
+/// let monthly: IADBSeries = IADBSeries {
+///     name: String::from("IUMBV34"), description: String::new(), data: vec![point(d(1, 31), 4.6)], metadata: None,
+/// };
+///
+/// let panel: IADBPanel = IADBPanel::join(&[daily, monthly], JoinType::AsOf { tolerance: None });
+///
+/// assert_eq!(panel.column(&SeriesCode::IUMBV34).unwrap(), &[Some(4.6), Some(4.6)]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IADBPanel {
+    dates: Vec<NaiveDate>,
+    columns: Vec<PanelColumn>,
+}
+
+impl IADBPanel {
+
+    /// Joins the series on date (Note: The columns are named after the series and are in the order of `series`).
+    ///
+    /// # Input
+    /// - `series`: Series to join
+    /// - `join`: Method of matching the dates of the series
+    pub fn join(series: &[IADBSeries], join: JoinType) -> Self {
+        let dates: Vec<NaiveDate> = match join {
+            JoinType::Inner => {
+                let mut dates: Vec<NaiveDate> = series.first().map(|v| v.data.iter().map(|p| p.date ).collect() ).unwrap_or_default();
+                for other in series.iter().skip(1) {
+                    let other_dates: BTreeSet<NaiveDate> = other.data.iter().map(|v| v.date ).collect();
+                    dates.retain(|v| other_dates.contains(v) );
+                }
+                dates.into_iter().collect::<BTreeSet<NaiveDate>>().into_iter().collect()
+            },
+            JoinType::Outer => series.iter().flat_map(|v| v.data.iter().map(|p| p.date ) ).collect::<BTreeSet<NaiveDate>>().into_iter().collect(),
+            JoinType::AsOf { .. } => series.first().map(|v| v.data.iter().map(|p| p.date ).collect::<BTreeSet<NaiveDate>>().into_iter().collect() ).unwrap_or_default(),
+        };
+        let columns: Vec<PanelColumn> = series.iter().enumerate().map(|(i, v)| {
+            let values: Vec<Option<f64>> = match join {
+                JoinType::AsOf { tolerance } if 0 < i => as_of_values(&v.data, &dates, tolerance),
+                _ => {
+                    let values: HashMap<NaiveDate, Option<f64>> = v.data.iter().map(|p| (p.date, p.value) ).collect();
+                    dates.iter().map(|d| values.get(d).copied().flatten() ).collect()
+                },
+            };
+            PanelColumn { name: v.name.clone(), values, }
+        }).collect();
+        IADBPanel { dates, columns, }
+    }
+
+    /// Creates a panel from the dates and the named columns (Note: Every column must have one value per date).
+    #[cfg(feature = "arrow")]
+    pub(crate) fn from_columns(dates: Vec<NaiveDate>, columns: Vec<(String, Vec<Option<f64>>)>) -> Self {
+        let columns: Vec<PanelColumn> = columns.into_iter().map(|(name, values)| PanelColumn { name, values, } ).collect();
+        IADBPanel { dates, columns, }
+    }
+
+    /// Fills the missing values with the last non-missing value of the column.
+    ///
+    /// # Input
+    /// - `limit`: Maximum number of consecutive missing values that are filled (Note: `None` fills all of them)
+    pub fn forward_fill(mut self, limit: Option<usize>) -> Self {
+        for column in self.columns.iter_mut() {
+            let mut last: Option<f64> = None;
+            let mut filled: usize = 0;
+            for value in column.values.iter_mut() {
+                match value {
+                    Some(v) => { last = Some(*v); filled = 0; },
+                    None if limit.is_none_or(|l| filled < l ) => { *value = last; filled += 1; },
+                    None => (),
+                }
+            }
+        }
+        self
+    }
+
+    /// Removes the rows that have a missing value in any column.
+    pub fn drop_missing(mut self) -> Self {
+        let keep: Vec<bool> = (0..self.dates.len()).map(|i| self.columns.iter().all(|c| c.values[i].is_some() ) ).collect();
+        let mut rows = keep.iter();
+        self.dates.retain(|_| *rows.next().unwrap() );
+        for column in self.columns.iter_mut() {
+            let mut rows = keep.iter();
+            column.values.retain(|_| *rows.next().unwrap() );
+        }
+        self
+    }
+
+    /// Number of rows (dates) in the panel.
+    pub fn len(&self) -> usize {
+        self.dates.len()
+    }
+
+    /// Checks whether the panel has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.dates.is_empty()
+    }
+
+    /// Dates of the rows, sorted in ascending order.
+    pub fn dates(&self) -> &[NaiveDate] {
+        &self.dates
+    }
+
+    /// Names of the columns (i.e., the series codes of the joined series).
+    pub fn names(&self) -> Vec<&str> {
+        self.columns.iter().map(|v| v.name.as_str() ).collect()
+    }
+
+    /// Values of the column of the series, with one value per row.
+    pub fn column(&self, series_code: &SeriesCode) -> Option<&[Option<f64>]> {
+        self.column_by_name(&series_code.to_string())
+    }
+
+    /// Values of the column with the provided name, with one value per row.
+    pub fn column_by_name(&self, name: &str) -> Option<&[Option<f64>]> {
+        self.columns.iter().find(|v| v.name == name ).map(|v| v.values.as_slice() )
+    }
+
+    /// Date and the values of every column in the row.
+    pub fn row(&self, index: usize) -> Option<(NaiveDate, Vec<Option<f64>>)> {
+        let date: NaiveDate = *self.dates.get(index)?;
+        Some((date, self.columns.iter().map(|v| v.values[index] ).collect()))
+    }
+
+    /// Iterates over the rows of the panel.
+    pub fn rows(&self) -> impl Iterator<Item = (NaiveDate, Vec<Option<f64>>)> + '_ {
+        (0..self.len()).filter_map(|i| self.row(i) )
+    }
+
+    /// Column of the series as a series with one data point per row.
+    pub fn to_series(&self, series_code: &SeriesCode) -> Option<IADBSeries> {
+        let values: &[Option<f64>] = self.column(series_code)?;
+        let data: Vec<IADBDataPoint> = self.dates.iter().zip(values).map(|(date, value)| IADBDataPoint { date: *date, value: *value, } ).collect();
+        Some(IADBSeries { name: series_code.to_string(), description: series_code.description(), data, metadata: None, })
+    }
+}
+
+impl fmt::Display for IADBPanel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "IADB Panel: DATE,{}", self.names().join(","))?;
+        for (date, values) in self.rows() {
+            let values: Vec<String> = values.iter().map(|v| v.map(|v| v.to_string() ).unwrap_or_else(|| String::from("n/a") ) ).collect();
+            writeln!(f, "{},{}", date, values.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+
+/// Latest non-missing value of the series on or before every date, within the tolerance (in days).
+fn as_of_values(data: &[IADBDataPoint], dates: &[NaiveDate], tolerance: Option<u64>) -> Vec<Option<f64>> {
+    let mut observations: Vec<(NaiveDate, f64)> = data.iter().filter_map(|v| v.value.map(|value| (v.date, value) ) ).collect();
+    observations.sort_by_key(|v| v.0 );
+    dates.iter().map(|date| {
+        let index: usize = observations.partition_point(|v| v.0 <= *date ).checked_sub(1)?;
+        let (observation_date, value) = observations[index];
+        match tolerance {
+            Some(t) if t < (*date - observation_date).num_days() as u64 => None,
+            _ => Some(value),
+        }
+    }).collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    #[test]
+    fn unit_test_panel() {
+        use crate::{SeriesCode, schemas::{IADBSeries, IADBDataPoint}, panel::{IADBPanel, JoinType}};
+        let d = |month: u32, day: u32| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+        let series = |name: &str, data: Vec<(NaiveDate, Option<f64>)>| IADBSeries {
+            name: String::from(name), description: String::new(), metadata: None,
+            data: data.into_iter().map(|(date, value)| IADBDataPoint { date, value } ).collect(),
+        };
+        let sonia: IADBSeries = series("IUDSOIA", vec![(d(1, 30), Some(5.19)), (d(1, 31), None), (d(2, 1), Some(5.2)), (d(2, 2), Some(5.21))]);
+        let bank_rate: IADBSeries = series("IUDBEDR", vec![(d(1, 31), Some(5.25)), (d(2, 1), Some(5.25)), (d(2, 5), Some(5.25))]);
+        let mortgage: IADBSeries = series("IUMBV34", vec![(NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(), Some(4.9)), (d(1, 31), Some(4.6))]);
+        // Inner and outer joins
+        let inner: IADBPanel = IADBPanel::join(&[sonia.clone(), bank_rate.clone()], JoinType::Inner);
+        assert_eq!(inner.dates(), &[d(1, 31), d(2, 1)]);
+        assert_eq!(inner.column(&SeriesCode::IUDSOIA).unwrap(), &[None, Some(5.2)]);
+        assert_eq!(inner.clone().drop_missing().len(), 1);
+        let outer: IADBPanel = IADBPanel::join(&[sonia.clone(), bank_rate.clone()], JoinType::Outer);
+        assert_eq!(outer.len(), 5);
+        assert_eq!(outer.column(&SeriesCode::IUDBEDR).unwrap(), &[None, Some(5.25), Some(5.25), None, Some(5.25)]);
+        let filled: IADBPanel = outer.forward_fill(Some(1));
+        assert_eq!(filled.column(&SeriesCode::IUDSOIA).unwrap(), &[Some(5.19), Some(5.19), Some(5.2), Some(5.21), Some(5.21)]);
+        assert_eq!(filled.column(&SeriesCode::IUDBEDR).unwrap()[0], None);
+        // As-of join matches the latest monthly observation on or before every daily date
+        let as_of: IADBPanel = IADBPanel::join(&[sonia.clone(), mortgage.clone()], JoinType::AsOf { tolerance: None });
+        assert_eq!(as_of.names(), vec!["IUDSOIA", "IUMBV34"]);
+        assert_eq!(as_of.column(&SeriesCode::IUMBV34).unwrap(), &[Some(4.9), Some(4.6), Some(4.6), Some(4.6)]);
+        let as_of: IADBPanel = IADBPanel::join(&[sonia, mortgage], JoinType::AsOf { tolerance: Some(1) });
+        assert_eq!(as_of.column_by_name("IUMBV34").unwrap(), &[None, Some(4.6), Some(4.6), None]);
+        assert_eq!(as_of.to_series(&SeriesCode::IUMBV34).unwrap().data[1].value, Some(4.6));
+    }
+}
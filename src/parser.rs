@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+use csv::{Reader, ReaderBuilder, StringRecord};
+use chrono::NaiveDate;
+use crate::error::Error;
+use crate::{schemas::{IADBDataPoint, IADBSeriesMetadata}, utils::{CSVF, MissingValuePolicy, REQUEST_DATE_FORMAT, RESPONSE_DATE_FORMAT}};
+
+
+/// Series deserialized from the response.
+#[derive(Debug, Default)]
+pub(crate) struct ParsedSeries {
+    /// Data points of the series.
+    pub data: Vec<IADBDataPoint>,
+    /// Title rows of the series (e.g., description), keyed by the upper-case column names of the titles section.
+    pub titles: HashMap<String, String>,
+}
+
+impl ParsedSeries {
+
+    /// Finds the first non-empty title among the columns with the provided names.
+    fn title(&self, names: &[&str]) -> Option<String> {
+        names.iter().filter_map(|n| self.titles.get(*n) ).find(|v| !v.is_empty() ).cloned()
+    }
+
+    /// Metadata of the series from the titles section, if the response has one.
+    pub fn metadata(&self) -> Option<IADBSeriesMetadata> {
+        if self.titles.is_empty() {
+            return None;
+        }
+        let last_updated: Option<NaiveDate> = self.title(&["LAST UPDATED", "LAST_UPDATED", "LAST UPDATE"]).and_then(|v| {
+            [RESPONSE_DATE_FORMAT, REQUEST_DATE_FORMAT, "%Y-%m-%d"].iter().find_map(|f| NaiveDate::parse_from_str(&v, f).ok() )
+        });
+        Some(IADBSeriesMetadata {
+            title: self.title(&["TITLE", "DESCRIPTION", "SERIES DESCRIPTION"]),
+            units: self.title(&["UNITS", "UNIT"]),
+            frequency: self.title(&["FREQUENCY"]),
+            seasonal_adjustment: self.title(&["SEASONAL ADJUSTMENT", "SEASONAL_ADJUSTMENT", "SEASONALLY ADJUSTED"]),
+            source: self.title(&["SOURCE"]),
+            last_updated,
+        })
+    }
+}
+
+
+/// Deserializes the CSV response in any of the layouts defined by `CSVF`.
+///
+/// Note: The layouts with titles (`TT` and `CT`) start with a titles section that describes the series,
+/// which is followed by the data section whose header contains the `DATE` column. The tabular layouts (`TT` and `TN`)
+/// have one value column per series code, while the columnar layouts (`CT` and `CN`) have one row per series code and date.
+/// The returned vector contains one series per requested series code, in the order of `series_codes`.
+pub(crate) fn parse_response(body: &str, layout: &CSVF, missing_values: &MissingValuePolicy, series_codes: &[String]) -> Result<Vec<ParsedSeries>, Error> {
+    let (titles_section, data_section) = split_sections(body)?;
+    let data: Vec<Vec<IADBDataPoint>> = match layout {
+        CSVF::TT | CSVF::TN => parse_table(data_section, missing_values, series_codes)?,
+        CSVF::CT | CSVF::CN => parse_columns(data_section, missing_values, series_codes)?,
+    };
+    let mut titles: Vec<HashMap<String, String>> = parse_titles(titles_section, series_codes)?;
+    let series: Vec<ParsedSeries> = data.into_iter().zip(titles.drain(..))
+        .map(|(data, titles)| ParsedSeries { data, titles, } )
+        .collect();
+    Ok(series)
+}
+
+
+/// Splits the response into the titles section and the data section, which starts with the header that contains the `DATE` column.
+///
+/// Note: If there is no such header, the whole response is treated as the data section.
+fn split_sections(body: &str) -> Result<(&str, &str), Error> {
+    let mut rdr: Reader<&[u8]> = ReaderBuilder::new().has_headers(false).flexible(true).from_reader(body.as_bytes());
+    let mut record: StringRecord = StringRecord::new();
+    loop {
+        let position: u64 = rdr.position().byte();
+        if !rdr.read_record(&mut record)? {
+            return Ok(("", body));
+        }
+        if record.iter().any(|v| v.trim().eq_ignore_ascii_case("DATE") ) {
+            let split: usize = position as usize;
+            return Ok((&body[..split], &body[split..]));
+        }
+    }
+}
+
+
+/// Finds the column whose header matches any of the names, defaulting to the provided position.
+fn find_column(headers: &StringRecord, names: &[&str], default: usize) -> usize {
+    headers.iter().position(|h| names.iter().any(|n| h.trim().eq_ignore_ascii_case(n) ) ).unwrap_or(default)
+}
+
+
+/// Matches the series name in the response to the index of the requested series code.
+///
+/// Note: Names that are not series codes (e.g., titles when `UsingCodes=N`) are matched by the order in which they first appear.
+fn match_series(name: &str, series_codes: &[String], matched: &mut Vec<String>) -> Option<usize> {
+    if let Some(i) = series_codes.iter().position(|v| v.eq_ignore_ascii_case(name.trim()) ) {
+        return Some(i);
+    }
+    match matched.iter().position(|v| v == name ) {
+        Some(i) => Some(i),
+        None => {
+            matched.push(String::from(name));
+            (matched.len() <= series_codes.len()).then_some(matched.len() - 1)
+        },
+    }
+}
+
+
+/// Parses a date in the format of the CSV response.
+fn parse_date(date: &str) -> Result<NaiveDate, Error> {
+    Ok(NaiveDate::parse_from_str(date.trim(), RESPONSE_DATE_FORMAT)?)
+}
+
+
+/// Parses the value of an observation according to the policy, returning `None` if the observation is left out of the series.
+fn parse_value(value: &str, date: NaiveDate, series_code: &str, missing_values: &MissingValuePolicy) -> Result<Option<IADBDataPoint>, Error> {
+    let value: &str = value.trim();
+    match (value.parse::<f64>(), missing_values) {
+        (Ok(v), _) if v.is_finite() => Ok(Some(IADBDataPoint { date, value: Some(v), })),
+        (_, MissingValuePolicy::KeepAsMissing) => Ok(Some(IADBDataPoint { date, value: None, })),
+        (_, MissingValuePolicy::Error) if !value.is_empty() => {
+            Err(Error::InvalidValue { series_code: String::from(series_code), date, value: String::from(value), })
+        },
+        _ => Ok(None),
+    }
+}
+
+
+/// Deserializes a CSV table with the date column followed by one value column per series code.
+///
+/// Note: Columns are matched to the series codes by their headers, and by position if the headers are not series codes.
+/// Empty cells are handled by the policy since not every series has an observation on every date.
+fn parse_table(body: &str, missing_values: &MissingValuePolicy, series_codes: &[String]) -> Result<Vec<Vec<IADBDataPoint>>, Error> {
+    let mut rdr: Reader<&[u8]> = ReaderBuilder::new().has_headers(true).flexible(true).from_reader(body.as_bytes());
+    // Match series codes to the columns of the table
+    let headers: StringRecord = rdr.headers()?.clone();
+    let date_column: usize = find_column(&headers, &["DATE"], 0);
+    let mut columns: Vec<usize> = Vec::<usize>::new();
+    for (i, series_code) in series_codes.iter().enumerate() {
+        let column: usize = match headers.iter().position(|h| h.trim().eq_ignore_ascii_case(series_code)) {
+            Some(v) => v,
+            None => i + 1,
+        };
+        if headers.len() <= column {
+            return Err(Error::MissingColumn { series_code: series_code.clone() });
+        }
+        columns.push(column);
+    }
+    // Deserialize data
+    let mut data: Vec<Vec<IADBDataPoint>> = series_codes.iter().map(|_| Vec::<IADBDataPoint>::new()).collect();
+    for record in rdr.records() {
+        let record: StringRecord = record?;
+        let date: NaiveDate = match record.get(date_column) {
+            Some(v) if !v.trim().is_empty() => parse_date(v)?,
+            _ => continue,
+        };
+        for ((series_data, column), series_code) in data.iter_mut().zip(columns.iter()).zip(series_codes.iter()) {
+            if let Some(v) = parse_value(record.get(*column).unwrap_or(""), date, series_code, missing_values)? {
+                series_data.push(v);
+            }
+        }
+    }
+    Ok(data)
+}
+
+
+/// Deserializes a CSV table with one row per series code and date, that has the date, series and value columns.
+///
+/// Note: Columns are matched by their headers, and default to the order of date, series and value.
+fn parse_columns(body: &str, missing_values: &MissingValuePolicy, series_codes: &[String]) -> Result<Vec<Vec<IADBDataPoint>>, Error> {
+    let mut rdr: Reader<&[u8]> = ReaderBuilder::new().has_headers(true).flexible(true).from_reader(body.as_bytes());
+    let headers: StringRecord = rdr.headers()?.clone();
+    let date_column: usize = find_column(&headers, &["DATE"], 0);
+    let series_column: usize = find_column(&headers, &["SERIES", "SERIES CODE", "SERIES_CODE", "CODE"], 1);
+    let value_column: usize = find_column(&headers, &["VALUE"], 2);
+    // Deserialize data
+    let mut data: Vec<Vec<IADBDataPoint>> = series_codes.iter().map(|_| Vec::<IADBDataPoint>::new()).collect();
+    let mut matched: Vec<String> = Vec::<String>::new();
+    for record in rdr.records() {
+        let record: StringRecord = record?;
+        let date: NaiveDate = match record.get(date_column) {
+            Some(v) if !v.trim().is_empty() => parse_date(v)?,
+            _ => continue,
+        };
+        let index: usize = match match_series(record.get(series_column).unwrap_or(""), series_codes, &mut matched) {
+            Some(v) => v,
+            None => continue,
+        };
+        if let Some(v) = parse_value(record.get(value_column).unwrap_or(""), date, &series_codes[index], missing_values)? {
+            data[index].push(v);
+        }
+    }
+    Ok(data)
+}
+
+
+/// Deserializes the titles section, which has a header followed by one row per series code.
+///
+/// Note: Rows are matched to the series codes by their `SERIES` column (or the first column), and by order otherwise.
+fn parse_titles(body: &str, series_codes: &[String]) -> Result<Vec<HashMap<String, String>>, Error> {
+    let mut titles: Vec<HashMap<String, String>> = series_codes.iter().map(|_| HashMap::<String, String>::new() ).collect();
+    if body.trim().is_empty() {
+        return Ok(titles);
+    }
+    let mut rdr: Reader<&[u8]> = ReaderBuilder::new().has_headers(true).flexible(true).from_reader(body.as_bytes());
+    let headers: Vec<String> = rdr.headers()?.iter().map(|h| h.trim().to_uppercase() ).collect();
+    let series_column: usize = headers.iter().position(|h| h == "SERIES" || h == "SERIES CODE" ).unwrap_or(0);
+    let mut matched: Vec<String> = Vec::<String>::new();
+    for record in rdr.records() {
+        let record: StringRecord = record?;
+        if record.iter().all(|v| v.trim().is_empty() ) {
+            continue;
+        }
+        let index: usize = match match_series(record.get(series_column).unwrap_or(""), series_codes, &mut matched) {
+            Some(v) => v,
+            None => continue,
+        };
+        for (header, value) in headers.iter().zip(record.iter()) {
+            titles[index].insert(header.clone(), String::from(value.trim()));
+        }
+    }
+    Ok(titles)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use crate::{utils::{CSVF, MissingValuePolicy}, parser::{ParsedSeries, parse_response}};
+
+    /// Checks the series deserialized from the fixtures of `IUMBV34` and `IUMBV37`, requested in the reverse order.
+    ///
+    /// Note: The fixtures are hand-written (see `tests/fixtures/synthetic/README.md`), so the tests check the parser against
+    /// the documented layouts rather than against captured IADB responses.
+    fn check_fixture(body: &str, layout: &CSVF) -> Vec<ParsedSeries> {
+        let series_codes: Vec<String> = vec![String::from("IUMBV37"), String::from("IUMBV34")];
+        let series: Vec<ParsedSeries> = parse_response(body, layout, &MissingValuePolicy::Skip, &series_codes).unwrap();
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].data.len(), 3);
+        assert_eq!(series[0].data[0].date, NaiveDate::from_ymd_opt(2000, 1, 31).unwrap());
+        assert_eq!(series[0].data[0].value, Some(6.88));
+        assert_eq!(series[1].data.len(), 3);
+        assert_eq!(series[1].data[2].date, NaiveDate::from_ymd_opt(2000, 3, 31).unwrap());
+        assert_eq!(series[1].data[2].value, Some(6.67));
+        series
+    }
+
+    #[test]
+    fn unit_test_parse_tabular() {
+        let series: Vec<ParsedSeries> = check_fixture(include_str!("../tests/fixtures/synthetic/iumbv34_iumbv37_tt.csv"), &CSVF::TT);
+        assert!(series[1].titles["DESCRIPTION"].contains("2 year (75% LTV)"));
+        let series: Vec<ParsedSeries> = check_fixture(include_str!("../tests/fixtures/synthetic/iumbv34_iumbv37_tn.csv"), &CSVF::TN);
+        assert!(series[0].titles.is_empty());
+    }
+
+    #[test]
+    fn unit_test_parse_columnar() {
+        let series: Vec<ParsedSeries> = check_fixture(include_str!("../tests/fixtures/synthetic/iumbv34_iumbv37_ct.csv"), &CSVF::CT);
+        assert!(series[0].titles["DESCRIPTION"].contains("3 year (75% LTV)"));
+        let series: Vec<ParsedSeries> = check_fixture(include_str!("../tests/fixtures/synthetic/iumbv34_iumbv37_cn.csv"), &CSVF::CN);
+        assert!(series[1].titles.is_empty());
+    }
+
+    #[test]
+    fn unit_test_parse_metadata() {
+        use crate::schemas::IADBSeriesMetadata;
+        // Response to `UsingCodes=N` and `VFD=Y`, where the series are labelled with their titles
+        let body: &str = include_str!("../tests/fixtures/synthetic/iudsoia_iudbedr_tt_vfd.csv");
+        let series_codes: Vec<String> = vec![String::from("IUDSOIA"), String::from("IUDBEDR")];
+        let series: Vec<ParsedSeries> = parse_response(body, &CSVF::TT, &MissingValuePolicy::Skip, &series_codes).unwrap();
+        assert_eq!(series[0].data.len(), 3);
+        assert_eq!(series[1].data[0].value, Some(5.25));
+        let metadata: IADBSeriesMetadata = series[0].metadata().unwrap();
+        assert_eq!(metadata.title.as_deref(), Some("Daily Sterling overnight index average (SONIA) rate"));
+        assert_eq!(metadata.units.as_deref(), Some("Percent"));
+        assert_eq!(metadata.frequency.as_deref(), Some("Daily"));
+        assert_eq!(metadata.seasonal_adjustment.as_deref(), Some("Not seasonally adjusted"));
+        assert_eq!(metadata.source.as_deref(), Some("Bank of England"));
+        assert_eq!(metadata.last_updated, NaiveDate::from_ymd_opt(2024, 1, 8));
+        assert_eq!(series[1].metadata().unwrap().title.as_deref(), Some("Official Bank Rate"));
+    }
+
+    #[test]
+    fn unit_test_parse_table() {
+        let body: &str = "DATE,IUMBV34,IUMBV37\n31 Jan 2000,6.5,\n29 Feb 2000,6.6,6.9\n";
+        let series_codes: Vec<String> = vec![String::from("IUMBV37"), String::from("IUMBV34")];
+        let series: Vec<ParsedSeries> = parse_response(body, &CSVF::TN, &MissingValuePolicy::Skip, &series_codes).unwrap();
+        assert_eq!(series[0].data.len(), 1);
+        assert_eq!(series[0].data[0].value, Some(6.9));
+        assert_eq!(series[1].data.len(), 2);
+        assert_eq!(series[1].data[0].date, NaiveDate::from_ymd_opt(2000, 1, 31).unwrap());
+    }
+
+    #[test]
+    fn unit_test_missing_values() {
+        use crate::error::Error;
+        let body: &str = "DATE,IUMBV34\n31 Jan 2000,6.59\n29 Feb 2000,n/a\n31 Mar 2000,\n30 Apr 2000,*\n31 May 2000,6.72\n";
+        let series_codes: Vec<String> = vec![String::from("IUMBV34")];
+        let series: Vec<ParsedSeries> = parse_response(body, &CSVF::TN, &MissingValuePolicy::Skip, &series_codes).unwrap();
+        assert_eq!(series[0].data.len(), 2);
+        let series: Vec<ParsedSeries> = parse_response(body, &CSVF::TN, &MissingValuePolicy::KeepAsMissing, &series_codes).unwrap();
+        let values: Vec<Option<f64>> = series[0].data.iter().map(|v| v.value ).collect();
+        assert_eq!(values, vec![Some(6.59), None, None, None, Some(6.72)]);
+        match parse_response(body, &CSVF::TN, &MissingValuePolicy::Error, &series_codes) {
+            Err(Error::InvalidValue { series_code, date, value }) => {
+                assert_eq!((series_code.as_str(), value.as_str()), ("IUMBV34", "n/a"));
+                assert_eq!(date, NaiveDate::from_ymd_opt(2000, 2, 29).unwrap());
+            },
+            _ => panic!("Expected an invalid value error"),
+        }
+    }
+}
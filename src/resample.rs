@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
+use chrono::NaiveDate;
+use crate::{catalog::Frequency, schemas::{IADBSeries, IADBSeriesMetadata, IADBDataPoint}};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Method of aggregating the observations of a period into a single value.
+pub enum Aggregation {
+    /// First observation of the period.
+    First,
+    /// Last observation of the period (e.g., end-month rate).
+    Last,
+    /// Average of the observations of the period (e.g., monthly average rate).
+    Mean,
+    /// Lowest observation of the period.
+    Min,
+    /// Highest observation of the period.
+    Max,
+}
+
+impl Aggregation {
+
+    /// Aggregates the values (Note: The values must not be empty).
+    fn apply(&self, values: &[f64]) -> f64 {
+        match self {
+            Aggregation::First => values[0],
+            Aggregation::Last => values[values.len() - 1],
+            Aggregation::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            Aggregation::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            Aggregation::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Method of filling the dates between the observations when converting to a higher frequency.
+pub enum Upsampling {
+    /// Value of the latest observation on or before the date.
+    Step,
+    /// Straight line between the neighbouring observations, by the number of days.
+    Linear,
+}
+
+
+impl IADBSeries {
+
+    /// Converts the series to a lower frequency (e.g., daily to monthly) by aggregating the observations of every period.
+    ///
+    /// Note: Observations are dated at the end of their period, which is the convention of the IADB (e.g., monthly data is dated
+    /// on the last calendar day of the month). Missing observations are ignored, and periods without observations are left out.
+    ///
+    /// # Input
+    /// - `frequency`: Frequency of the resampled series
+    /// - `aggregation`: Method of aggregating the observations of a period
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use iadb_api::{NaiveDate, IADBSeries, IADBDataPoint, Frequency, resample::Aggregation};
+    ///
+    /// let data: Vec<IADBDataPoint> = (1..=60).map(|day| IADBDataPoint {
+    ///     date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap() + chrono::Days::new(day), value: Some(day as f64),
+    /// }).collect();
+    /// let series: IADBSeries = IADBSeries { name: String::from("IUDSOIA"), description: String::new(), data, metadata: None, };
+    ///
+    /// let monthly: IADBSeries = series.resample(Frequency::Monthly, Aggregation::Last);
+    ///
+    /// assert_eq!(monthly.data[0].date, NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+    /// assert_eq!(monthly.data[1].date, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    /// ```
+    pub fn resample(&self, frequency: Frequency, aggregation: Aggregation) -> IADBSeries {
+        let mut periods: BTreeMap<NaiveDate, Vec<(NaiveDate, f64)>> = BTreeMap::new();
+        for point in self.data.iter() {
+            if let Some(value) = point.value {
+                periods.entry(frequency.period_end(&point.date)).or_default().push((point.date, value));
+            }
+        }
+        let data: Vec<IADBDataPoint> = periods.into_iter().map(|(date, mut observations)| {
+            observations.sort_by_key(|v| v.0 );
+            let values: Vec<f64> = observations.into_iter().map(|v| v.1 ).collect();
+            IADBDataPoint { date, value: Some(aggregation.apply(&values)), }
+        }).collect();
+        self.with_data(data, frequency)
+    }
+
+    /// Converts the series to a higher frequency (e.g., monthly to daily) by filling the dates between the observations.
+    ///
+    /// Note: The dates of the upsampled series are the ends of the periods (e.g., every calendar day for `Frequency::Daily`)
+    /// from the first observation to the last observation. Missing observations are ignored.
+    ///
+    /// # Input
+    /// - `frequency`: Frequency of the upsampled series
+    /// - `upsampling`: Method of filling the dates between the observations
+    pub fn upsample(&self, frequency: Frequency, upsampling: Upsampling) -> IADBSeries {
+        let mut observations: Vec<(NaiveDate, f64)> = self.data.iter().filter_map(|v| v.value.map(|value| (v.date, value) ) ).collect();
+        observations.sort_by_key(|v| v.0 );
+        let (first, last) = match (observations.first(), observations.last()) {
+            (Some(first), Some(last)) => (first.0, last.0),
+            _ => return self.with_data(vec![], frequency),
+        };
+        let mut data: Vec<IADBDataPoint> = Vec::new();
+        // First period end on or after the first observation
+        let mut date: NaiveDate = frequency.period_end(&first);
+        while date <= last {
+            let index: usize = observations.partition_point(|v| v.0 <= date ) - 1;
+            let (previous_date, previous_value) = observations[index];
+            let value: f64 = match (upsampling, observations.get(index + 1)) {
+                (Upsampling::Linear, Some((next_date, next_value))) => {
+                    let weight: f64 = (date - previous_date).num_days() as f64 / (*next_date - previous_date).num_days() as f64;
+                    previous_value + (next_value - previous_value) * weight
+                },
+                _ => previous_value,
+            };
+            data.push(IADBDataPoint { date, value: Some(value), });
+            date = frequency.next_period_end(&date);
+        }
+        self.with_data(data, frequency)
+    }
+
+    /// Copy of the series with the provided data points, with the frequency in the metadata updated.
+    fn with_data(&self, data: Vec<IADBDataPoint>, frequency: Frequency) -> IADBSeries {
+        let metadata: Option<IADBSeriesMetadata> = self.metadata.clone().map(|v| IADBSeriesMetadata { frequency: Some(frequency.to_string()), ..v } );
+        IADBSeries { name: self.name.clone(), description: self.description.clone(), data, metadata, }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    #[test]
+    fn unit_test_resample() {
+        use crate::{catalog::Frequency, resample::{Aggregation, Upsampling}, schemas::{IADBSeries, IADBDataPoint}};
+        let d = |year: i32, month: u32, day: u32| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        assert_eq!(Frequency::Weekly.period_end(&d(2024, 2, 28)), d(2024, 3, 3));
+        assert_eq!(Frequency::Quarterly.period_end(&d(2024, 11, 5)), d(2024, 12, 31));
+        assert_eq!(Frequency::Monthly.next_period_end(&d(2024, 1, 31)), d(2024, 2, 29));
+        // Downsampling
+        let data: Vec<IADBDataPoint> = vec![
+            IADBDataPoint { date: d(2024, 1, 30), value: Some(5.19) }, IADBDataPoint { date: d(2024, 1, 31), value: Some(5.2) },
+            IADBDataPoint { date: d(2024, 2, 1), value: Some(5.22) }, IADBDataPoint { date: d(2024, 2, 2), value: None },
+            IADBDataPoint { date: d(2024, 4, 2), value: Some(5.21) },
+        ];
+        let daily: IADBSeries = IADBSeries { name: String::from("IUDSOIA"), description: String::new(), data, metadata: None, };
+        let monthly: IADBSeries = daily.resample(Frequency::Monthly, Aggregation::Mean);
+        let dates: Vec<NaiveDate> = monthly.data.iter().map(|v| v.date ).collect();
+        assert_eq!(dates, vec![d(2024, 1, 31), d(2024, 2, 29), d(2024, 4, 30)]);
+        assert!((monthly.data[0].value.unwrap() - 5.195).abs() < 1e-12);
+        let quarterly: IADBSeries = daily.resample(Frequency::Quarterly, Aggregation::Max);
+        assert_eq!(quarterly.data[0].value, Some(5.22));
+        assert_eq!(daily.resample(Frequency::Annual, Aggregation::First).data[0].value, Some(5.19));
+        // Upsampling
+        let step: IADBSeries = monthly.upsample(Frequency::Daily, Upsampling::Step);
+        assert_eq!(step.data.len(), 91);
+        assert_eq!(step.data[1].value, Some(5.195));
+        let linear: IADBSeries = monthly.upsample(Frequency::Daily, Upsampling::Linear);
+        assert!((linear.data[29].value.unwrap() - 5.22).abs() < 1e-12);
+        assert!(linear.data[1].value.unwrap() > 5.195);
+    }
+}
@@ -0,0 +1,166 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::Mutex;
+use crate::error::{Error, ErrorKind};
+
+
+/// Policy that decides whether and when a failed request is sent again.
+///
+/// Note: The delay before the `n`-th retry is `initial_backoff * multiplier^(n-1)`, capped at `max_backoff`,
+/// and randomly shortened by up to `jitter` (fraction of the delay) so that concurrent jobs do not retry in lockstep.
+/// HTML pages that are not recognised as IADB errors (`ErrorKind::UnexpectedResponse`) are not retried by default,
+/// but can be added with `retryable` (e.g., to wait out a maintenance page).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: f64,
+    retryable: Vec<ErrorKind>,
+}
+
+impl RetryPolicy {
+
+    /// Creates a policy that sends every request only once.
+    pub fn none() -> Self {
+        RetryPolicy { max_attempts: 1, ..RetryPolicy::default() }
+    }
+
+    /// Sets the maximum number of attempts, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper bound of the delay between two attempts.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the factor by which the delay grows after every retry.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Sets the fraction of the delay (between `0.0` and `1.0`) that is randomised.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the kinds of errors after which the request is sent again.
+    pub fn retryable(mut self, retryable: Vec<ErrorKind>) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
+    /// Checks whether the request that failed on the given attempt (starting from `1`) should be sent again.
+    pub fn should_retry(&self, error: &Error, attempt: u32) -> bool {
+        attempt < self.max_attempts && self.retryable.contains(&error.kind())
+    }
+
+    /// Delay before the retry that follows the given failed attempt (starting from `1`).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent: i32 = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let backoff: f64 = (self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent)).min(self.max_backoff.as_secs_f64());
+        Duration::from_secs_f64(backoff * (1.0 - self.jitter * random_fraction()))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3, initial_backoff: Duration::from_millis(500), max_backoff: Duration::from_secs(30), multiplier: 2.0, jitter: 0.2,
+            retryable: vec![ErrorKind::Connection, ErrorKind::Timeout, ErrorKind::Throttled, ErrorKind::ServerError],
+        }
+    }
+}
+
+
+/// Client-side rate limiter that spaces out the requests evenly.
+///
+/// Note: The rate limiter is shared by all clones of the `IADBClient` it is attached to.
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    next_request: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+
+    /// Creates a rate limiter that allows at most `max_requests` requests per `period`.
+    pub fn new(max_requests: u32, period: Duration) -> Self {
+        RateLimiter { interval: period / max_requests.max(1), next_request: Mutex::new(None), }
+    }
+
+    /// Waits until the next request is allowed to be sent.
+    pub async fn acquire(&self) {
+        let wait_until: Instant = {
+            let mut next_request = self.next_request.lock().await;
+            let now: Instant = Instant::now();
+            let slot: Instant = match *next_request {
+                Some(v) if now < v => v,
+                _ => now,
+            };
+            *next_request = Some(slot + self.interval);
+            slot
+        };
+        tokio::time::sleep_until(wait_until.into()).await;
+    }
+}
+
+
+/// Pseudo-random number in `[0, 1)` used for jitter.
+fn random_fraction() -> f64 {
+    static STATE: AtomicU64 = AtomicU64::new(0);
+    let seed: u64 = SystemTime::now().duration_since(UNIX_EPOCH).map(|v| v.as_nanos() as u64 ).unwrap_or(0);
+    // SplitMix64
+    let mut z: u64 = STATE.fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed) ^ seed;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn unit_test_retry_policy() {
+        use crate::{error::{Error, ErrorKind}, retry::RetryPolicy};
+        let policy: RetryPolicy = RetryPolicy::default().initial_backoff(Duration::from_secs(1)).max_backoff(Duration::from_secs(3)).jitter(0.0);
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(3));
+        assert!(policy.should_retry(&Error::HttpStatus(503), 1));
+        assert!(!policy.should_retry(&Error::HttpStatus(503), 3));
+        assert!(!policy.should_retry(&Error::UnknownSeries(String::from("IUDXXXX")), 1));
+        assert!(!policy.should_retry(&Error::UnexpectedResponse { snippet: String::from("Service unavailable") }, 1));
+        let policy: RetryPolicy = policy.retryable(vec![ErrorKind::InvalidRequest]);
+        assert!(policy.should_retry(&Error::InvalidDateRange, 1));
+        let backoff: Duration = RetryPolicy::default().backoff(1);
+        assert!(Duration::from_millis(400) <= backoff && backoff <= Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn unit_test_rate_limiter() {
+        use crate::retry::RateLimiter;
+        let rate_limiter: RateLimiter = RateLimiter::new(10, Duration::from_millis(200));
+        let start: Instant = Instant::now();
+        for _ in 0..3 {
+            rate_limiter.acquire().await;
+        }
+        assert!(Duration::from_millis(40) <= start.elapsed());
+    }
+}
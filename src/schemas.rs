@@ -1,39 +1,141 @@
 use std::fmt;
 use serde::{Serialize, Deserialize};
+use chrono::NaiveDate;
+use crate::{SeriesCode, catalog::Frequency};
 
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// Data series.
 pub struct IADBSeries {
     /// IADB series code. 
     pub name: String,
     pub description: String,
     pub data: Vec<IADBDataPoint>,
+    /// Metadata of the series (Note: Only populated if the metadata was requested).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<IADBSeriesMetadata>,
+}
+
+impl IADBSeries {
+
+    /// Merges the data points of another series into this series.
+    ///
+    /// Note: Data points of `other` replace the data points of this series with the same date, and the result is sorted by date.
+    pub fn merge(&mut self, other: IADBSeries) {
+        let mut data: Vec<IADBDataPoint> = other.data;
+        data.extend(std::mem::take(&mut self.data));
+        // Stable sort keeps the data points of `other` ahead of the existing data points with the same date
+        data.sort_by_key(|v| v.date );
+        data.dedup_by_key(|v| v.date );
+        self.data = data;
+        if other.metadata.is_some() {
+            self.metadata = other.metadata;
+        }
+    }
+
+    /// Returns a copy of the series with only the data points between `date_from` and `date_to` (inclusive).
+    pub fn between(&self, date_from: &NaiveDate, date_to: &NaiveDate) -> IADBSeries {
+        let data: Vec<IADBDataPoint> = self.data.iter().filter(|v| date_from <= &v.date && &v.date <= date_to ).cloned().collect();
+        IADBSeries { name: self.name.clone(), description: self.description.clone(), data, metadata: self.metadata.clone(), }
+    }
 }
 
 impl fmt::Display for IADBSeries {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "IADB Series: {} ({})\n", self.name, self.description)?;
+        writeln!(f, "IADB Series: {} ({})", self.name, self.description)?;
         for item in self.data.iter() {
-            write!(f, "{}\n", item.to_string())?;
+            writeln!(f, "{}", item)?;
         }
         Ok(())
     }
 }
 
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+/// Metadata of the series from the titles section of the IADB response.
+pub struct IADBSeriesMetadata {
+    /// Full title (description) of the series.
+    pub title: Option<String>,
+    /// Units of the values (e.g., `Percent`).
+    pub units: Option<String>,
+    /// Frequency of the observations (e.g., `Monthly`).
+    pub frequency: Option<String>,
+    /// Seasonal adjustment of the values (e.g., `Not seasonally adjusted`).
+    pub seasonal_adjustment: Option<String>,
+    /// Source of the data.
+    pub source: Option<String>,
+    /// Date when the series was last updated.
+    pub last_updated: Option<NaiveDate>,
+}
+
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// Describes a single entry in the series.
 pub struct IADBDataPoint {
-    // #[serde(rename = "DATE")]
     /// Date of the data point.
-    pub date: String,
-    /// Value of the data point.
-    pub value: f64,
+    pub date: NaiveDate,
+    /// Value of the data point (Note: `None` if the observation is missing).
+    pub value: Option<f64>,
+}
+
+impl IADBDataPoint {
+
+    /// Checks whether the observation is missing.
+    pub fn is_missing(&self) -> bool {
+        self.value.is_none()
+    }
 }
 
 impl fmt::Display for IADBDataPoint {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "IADB Data Point ({}): {}", self.date, self.value)
+        match self.value {
+            Some(v) => write!(f, "IADB Data Point ({}): {}", self.date, v),
+            None => write!(f, "IADB Data Point ({}): n/a", self.date),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Series listed by the IADB series search.
+pub struct IADBSearchResult {
+    /// Series code (Note: Codes that are not defined as variants of `SeriesCode` are returned as `SeriesCode::Other`).
+    pub series_code: SeriesCode,
+    /// Description of the series.
+    pub description: String,
+    /// Frequency of the observations, if the search page lists it.
+    pub frequency: Option<Frequency>,
+}
+
+impl fmt::Display for IADBSearchResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.frequency {
+            Some(v) => write!(f, "IADB Search Result: {} ({}, {})", self.series_code, self.description, v),
+            None => write!(f, "IADB Search Result: {} ({})", self.series_code, self.description),
+        }
     }
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    #[test]
+    fn unit_test_merge() {
+        use crate::schemas::{IADBSeries, IADBDataPoint};
+        let d = |day: u32| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+        let mut series: IADBSeries = IADBSeries {
+            name: String::from("IUDSOIA"), description: String::new(),
+            data: vec![IADBDataPoint { date: d(2), value: Some(5.0) }, IADBDataPoint { date: d(3), value: Some(5.1) }], metadata: None,
+        };
+        let other: IADBSeries = IADBSeries {
+            name: String::from("IUDSOIA"), description: String::new(),
+            data: vec![IADBDataPoint { date: d(4), value: Some(5.3) }, IADBDataPoint { date: d(3), value: None }], metadata: None,
+        };
+        series.merge(other);
+        let values: Vec<Option<f64>> = series.data.iter().map(|v| v.value ).collect();
+        assert_eq!(values, vec![Some(5.0), None, Some(5.3)]);
+        assert_eq!(series.between(&d(3), &d(10)).data.len(), 2);
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+use reqwest::Url;
+use crate::error::Error;
+use crate::{SeriesCode, catalog::Frequency, client::IADBClient, schemas::IADBSearchResult, transport::TransportResponse, utils::{SNIPPET_LENGTH, html_to_text, with_retries}};
+
+
+/// Messages in the search page that indicate that no series matched the query.
+const NO_RESULTS_MESSAGES: [&str; 2] = ["no series found", "no results"];
+
+/// Sends the query to the IADB series search page and deserializes the listed series.
+///
+/// # Input
+/// - `client`: Client that sends the request
+/// - `query`: Search terms (e.g., `sonia`)
+///
+/// Note: A page that lists no series and does not say that nothing was found is reported as `Error::UnexpectedResponse`,
+/// so that a change in the layout of the search page is not mistaken for an empty result.
+pub(crate) async fn call_search_endpoint(client: &IADBClient, query: &str) -> Result<Vec<IADBSearchResult>, Error> {
+    let body: String = with_retries(client, || send_search_request(client, query) ).await?;
+    let results: Vec<IADBSearchResult> = parse_search_results(&body);
+    if results.is_empty() {
+        let text: String = html_to_text(&body);
+        let lowercase_text: String = text.to_lowercase();
+        if !NO_RESULTS_MESSAGES.iter().any(|m| lowercase_text.contains(m) ) {
+            return Err(Error::UnexpectedResponse { snippet: text.chars().take(SNIPPET_LENGTH).collect() });
+        }
+    }
+    Ok(results)
+}
+
+
+/// Sends a single request to the search page and returns the body of the response.
+async fn send_search_request(client: &IADBClient, query: &str) -> Result<String, Error> {
+    let url: Url = Url::parse_with_params(client.search_url(), &[("SearchText", query)])
+        .map_err(|e| Error::TransportError(format!("Invalid search URL `{}`: {}", client.search_url(), e)) )?;
+    let response: TransportResponse = client.transport().get(url.as_str()).await?;
+    if !(200..300).contains(&response.status) {
+        return Err(Error::HttpStatus(response.status));
+    }
+    Ok(response.text())
+}
+
+
+/// Deserializes the table rows of the search page that list a series code.
+///
+/// Note: The series code is the cell whose text looks like an IADB code, the description is the longest of the other cells,
+/// and the frequency is taken from a frequency cell or, if there is none, from the description.
+/// Series listed more than once are only returned once.
+pub(crate) fn parse_search_results(body: &str) -> Vec<IADBSearchResult> {
+    let mut results: Vec<IADBSearchResult> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    for row in elements(body, "tr") {
+        let cells: Vec<String> = elements(row, "td").into_iter().map(html_to_text).collect();
+        let code_index: usize = match cells.iter().position(|v| is_series_code(v) ) {
+            Some(v) => v,
+            None => continue,
+        };
+        if !seen.insert(cells[code_index].clone()) {
+            continue;
+        }
+        let description: String = cells.iter().enumerate()
+            .filter(|(i, v)| *i != code_index && parse_frequency(v).is_none() )
+            .map(|(_, v)| v )
+            .max_by_key(|v| v.len() ).cloned().unwrap_or_default();
+        let frequency: Option<Frequency> = cells.iter().find_map(|v| parse_frequency(v) ).or_else(|| frequency_from_description(&description) );
+        results.push(IADBSearchResult { series_code: SeriesCode::from(cells[code_index].clone()), description, frequency, });
+    }
+    results
+}
+
+
+/// Inner HTML of the elements with the provided tag name.
+///
+/// Note: An element ends at its closing tag or at the next element with the same tag name (i.e., nested elements with the same tag are not supported).
+fn elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    // ASCII lowercasing keeps the byte offsets of the original HTML
+    let lowercase_html: String = html.to_ascii_lowercase();
+    let opening_tag: String = format!("<{}", tag);
+    let closing_tag: String = format!("</{}", tag);
+    let is_opening_tag = |i: usize| lowercase_html[(i + opening_tag.len())..].starts_with(|c: char| c == '>' || c.is_ascii_whitespace() );
+    let mut elements: Vec<&str> = Vec::new();
+    let mut position: usize = 0;
+    while let Some(i) = lowercase_html[position..].find(&opening_tag).map(|i| position + i ) {
+        position = i + opening_tag.len();
+        if !is_opening_tag(i) {
+            continue;
+        }
+        let start: usize = match lowercase_html[i..].find('>') {
+            Some(v) => i + v + 1,
+            None => break,
+        };
+        let mut end: usize = lowercase_html[start..].find(&closing_tag).map(|v| start + v ).unwrap_or(html.len());
+        // Elements without a closing tag end at the next element with the same tag name
+        let mut next: usize = start;
+        while let Some(j) = lowercase_html[next..end].find(&opening_tag).map(|v| next + v ) {
+            if is_opening_tag(j) {
+                end = j;
+                break;
+            }
+            next = j + opening_tag.len();
+        }
+        elements.push(&html[start..end]);
+        position = start;
+    }
+    elements
+}
+
+
+/// Checks whether the text looks like an IADB series code (e.g., `IUDSOIA`).
+fn is_series_code(text: &str) -> bool {
+    (4..=10).contains(&text.len()) && text.starts_with(|c: char| c.is_ascii_uppercase() )
+        && text.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() )
+}
+
+
+/// Parses the name of a frequency (case-insensitive).
+fn parse_frequency(text: &str) -> Option<Frequency> {
+    match text.trim().to_lowercase().as_str() {
+        "daily" => Some(Frequency::Daily),
+        "weekly" => Some(Frequency::Weekly),
+        "monthly" => Some(Frequency::Monthly),
+        "quarterly" => Some(Frequency::Quarterly),
+        "annual" | "annually" => Some(Frequency::Annual),
+        _ => None,
+    }
+}
+
+
+/// Frequency from the description of the series, which either starts (e.g., `Daily ...`) or ends (e.g., `..., Monthly`) with it.
+fn frequency_from_description(description: &str) -> Option<Frequency> {
+    description.rsplit(',').next().and_then(parse_frequency)
+        .or_else(|| description.split_whitespace().next().and_then(parse_frequency) )
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn unit_test_parse_search_results() {
+        use crate::{SeriesCode, catalog::Frequency, schemas::IADBSearchResult, search::parse_search_results};
+        let results: Vec<IADBSearchResult> = parse_search_results(include_str!("../tests/fixtures/synthetic/search_sonia.html"));
+        let codes: Vec<String> = results.iter().map(|v| v.series_code.to_string() ).collect();
+        assert_eq!(codes, vec!["IUDSOIA", "IUMSOIA", "IUDZOS2", "IUQASOIA"]);
+        assert_eq!(results[0].series_code, SeriesCode::IUDSOIA);
+        assert_eq!(results[1].series_code, SeriesCode::Other(String::from("IUMSOIA")));
+        assert_eq!(results[1].description, "Monthly average of Sterling overnight index average (SONIA) rate");
+        assert_eq!(results[1].frequency, Some(Frequency::Monthly));
+        assert_eq!(results[3].frequency, Some(Frequency::Quarterly));
+        assert!(parse_search_results(include_str!("../tests/fixtures/synthetic/search_no_results.html")).is_empty());
+    }
+}
@@ -0,0 +1,234 @@
+use serde::{Serialize, Deserialize};
+use chrono::{NaiveDate, Datelike, Days, Weekday};
+use crate::error::Error;
+use crate::schemas::{IADBSeries, IADBDataPoint};
+
+
+/// Number of days in a year used to annualise sterling rates (ACT/365).
+pub const DAY_COUNT_BASIS: f64 = 365.0;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+/// Convention of selecting the daily rates that accrue over an interest period.
+///
+/// Note: Lags are counted in business days, which are the dates of the observations in the SONIA series.
+pub enum Convention {
+    /// Rate of each business day of the interest period, weighted by the calendar days of the interest period.
+    #[default]
+    Plain,
+    /// Rate of the business day that is the given number of business days earlier,
+    /// weighted by the calendar days of the interest period (also known as "lag").
+    Lookback { days: usize },
+    /// Rates and weights of the observation period, which starts and ends the given number of business days
+    /// before the interest period.
+    ObservationShift { days: usize },
+}
+
+
+/// Daily rate (in percent) and the number of calendar days it accrues for.
+struct Accrual {
+    rate: f64,
+    days: i64,
+}
+
+
+/// Non-missing observations of the series, sorted by date.
+fn observations(series: &IADBSeries) -> Vec<(NaiveDate, f64)> {
+    let mut observations: Vec<(NaiveDate, f64)> = series.data.iter().filter_map(|v| v.value.map(|value| (v.date, value) ) ).collect();
+    observations.sort_by_key(|v| v.0 );
+    observations
+}
+
+
+/// Checks that the series has an observation for every weekday before the date (exclusive) that is after the last observation.
+///
+/// Note: Weekends after the last observation accrue at the rate of the last observation, but a weekday after it could be a business day
+/// whose rate has not been published yet, so the rate of the last observation is not reused for it.
+fn check_end(series: &IADBSeries, observations: &[(NaiveDate, f64)], date: &NaiveDate) -> Result<(), Error> {
+    let mut day: NaiveDate = match observations.last() {
+        Some(v) => v.0 + Days::new(1),
+        None => return Err(Error::MissingObservation { series_code: series.name.clone(), date: *date, }),
+    };
+    while day < *date {
+        if !matches!(day.weekday(), Weekday::Sat | Weekday::Sun) {
+            return Err(Error::MissingObservation { series_code: series.name.clone(), date: day, });
+        }
+        day = day + Days::new(1);
+    }
+    Ok(())
+}
+
+
+/// Date that is the given number of business days before the date (Note: Dates that are not business days are first moved to the next business day).
+fn shift_date(series: &IADBSeries, observations: &[(NaiveDate, f64)], date: &NaiveDate, days: usize) -> Result<NaiveDate, Error> {
+    let index: usize = observations.partition_point(|v| v.0 < *date );
+    match index.checked_sub(days) {
+        Some(i) => Ok(observations[i].0),
+        None => Err(Error::MissingObservation { series_code: series.name.clone(), date: *date, }),
+    }
+}
+
+
+/// Daily rates that accrue over the interest period, and the number of calendar days that the rates are annualised over.
+///
+/// Note: Calendar days that are not business days (e.g., weekends and bank holidays) accrue at the rate of the preceding business day.
+fn accruals(series: &IADBSeries, start: &NaiveDate, end: &NaiveDate, convention: &Convention) -> Result<(Vec<Accrual>, i64), Error> {
+    if end <= start {
+        return Err(Error::InvalidDateRange);
+    }
+    let observations: Vec<(NaiveDate, f64)> = observations(series);
+    check_end(series, &observations, end)?;
+    let (period_start, period_end, lag) = match convention {
+        Convention::Plain => (*start, *end, 0),
+        Convention::Lookback { days } => (*start, *end, *days),
+        Convention::ObservationShift { days } => (
+            shift_date(series, &observations, start, *days)?, shift_date(series, &observations, end, *days)?, 0,
+        ),
+    };
+    let mut accruals: Vec<Accrual> = Vec::new();
+    let mut last_index: Option<usize> = None;
+    let mut date: NaiveDate = period_start;
+    while date < period_end {
+        // Latest business day on or before the date, and the business day whose rate applies to it
+        let index: Option<usize> = observations.partition_point(|v| v.0 <= date ).checked_sub(1);
+        let rate_index: usize = index.and_then(|i| i.checked_sub(lag) )
+            .ok_or(Error::MissingObservation { series_code: series.name.clone(), date, })?;
+        match accruals.last_mut() {
+            Some(accrual) if last_index == index => accrual.days += 1,
+            _ => accruals.push(Accrual { rate: observations[rate_index].1, days: 1, }),
+        }
+        last_index = index;
+        date = date + Days::new(1);
+    }
+    Ok((accruals, (period_end - period_start).num_days()))
+}
+
+
+/// Compounded-in-arrears SONIA rate (in percent, annualised) over the interest period.
+///
+/// Note: The rate is `(Π(1 + r(i) * n(i) / 365) - 1) * 365 / d`, where `r(i)` is the rate of the `i`-th business day, `n(i)` is the number of
+/// calendar days it accrues for, and `d` is the number of calendar days in the period. Business days are the dates of the observations in the series,
+/// so the series must cover the whole period (including the lookback or observation shift), and `Error::MissingObservation` is returned
+/// if a weekday of the period is after the last observation.
+///
+/// # Input
+/// - `series`: SONIA series (e.g., `SeriesCode::IUDSOIA`)
+/// - `start`: First day of the interest period
+/// - `end`: Last day of the interest period (exclusive)
+/// - `convention`: Convention of selecting the daily rates
+///
+/// # Examples
+///
+/// ```rust
+/// use iadb_api::{NaiveDate, IADBSeries, IADBDataPoint, sonia::{self, Convention}};
+///
+/// let d = |day: u32| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+/// let data: Vec<IADBDataPoint> = (2..=5).map(|day| IADBDataPoint { date: d(day), value: Some(5.19) } ).collect();
+/// let series: IADBSeries = IADBSeries { name: String::from("IUDSOIA"), description: String::new(), data, metadata: None, };
+///
+/// let rate: f64 = sonia::compounded_rate(&series, &d(2), &d(5), &Convention::Plain).unwrap();
+///
+/// assert!(5.19 < rate && rate < 5.2);
+/// ```
+pub fn compounded_rate(series: &IADBSeries, start: &NaiveDate, end: &NaiveDate, convention: &Convention) -> Result<f64, Error> {
+    let (accruals, days) = accruals(series, start, end, convention)?;
+    let growth: f64 = accruals.iter().map(|v| 1.0 + v.rate / 100.0 * v.days as f64 / DAY_COUNT_BASIS ).product();
+    Ok(100.0 * (growth - 1.0) * DAY_COUNT_BASIS / days as f64)
+}
+
+
+/// Simple (non-compounded) average SONIA rate (in percent) over the interest period, weighted by calendar days.
+///
+/// # Input
+/// - `series`: SONIA series (e.g., `SeriesCode::IUDSOIA`)
+/// - `start`: First day of the interest period
+/// - `end`: Last day of the interest period (exclusive)
+/// - `convention`: Convention of selecting the daily rates
+pub fn simple_average(series: &IADBSeries, start: &NaiveDate, end: &NaiveDate, convention: &Convention) -> Result<f64, Error> {
+    let (accruals, days) = accruals(series, start, end, convention)?;
+    Ok(accruals.iter().map(|v| v.rate * v.days as f64 ).sum::<f64>() / days as f64)
+}
+
+
+/// SONIA compounded index, with one value per business day from the base date (Note: The index values are not rounded).
+///
+/// Note: The index on a business day is `I(t) = I(t-1) * (1 + r(t-1) * n(t-1) / 365)`, where `t-1` is the preceding business day
+/// and `n(t-1)` is the number of calendar days between the two business days.
+///
+/// # Input
+/// - `series`: SONIA series (e.g., `SeriesCode::IUDSOIA`)
+/// - `base_date`: Date of the base value (Note: If it is not a business day, the index starts on the next business day)
+/// - `base_value`: Value of the index on the base date (e.g., `100.0`)
+pub fn compounded_index(series: &IADBSeries, base_date: &NaiveDate, base_value: f64) -> Result<IADBSeries, Error> {
+    let observations: Vec<(NaiveDate, f64)> = observations(series);
+    let start: usize = observations.partition_point(|v| v.0 < *base_date );
+    if start == observations.len() {
+        return Err(Error::MissingObservation { series_code: series.name.clone(), date: *base_date, });
+    }
+    let mut data: Vec<IADBDataPoint> = vec![IADBDataPoint { date: observations[start].0, value: Some(base_value), }];
+    let mut index: f64 = base_value;
+    for window in observations[start..].windows(2) {
+        let ((date, rate), (next_date, _)) = (window[0], window[1]);
+        index *= 1.0 + rate / 100.0 * (next_date - date).num_days() as f64 / DAY_COUNT_BASIS;
+        data.push(IADBDataPoint { date: next_date, value: Some(index), });
+    }
+    let description: String = format!("Compounded index of {} ({} = {})", series.name, observations[start].0, base_value);
+    Ok(IADBSeries { name: format!("{} Compounded Index", series.name), description, data, metadata: None, })
+}
+
+
+/// Compounded rate (in percent, annualised) between two dates of a compounded index, i.e., `(I(end) / I(start) - 1) * 365 / d`.
+///
+/// # Input
+/// - `index`: Compounded index (e.g., the output of `compounded_index`)
+/// - `start`: Date of the start value of the index
+/// - `end`: Date of the end value of the index
+pub fn rate_from_index(index: &IADBSeries, start: &NaiveDate, end: &NaiveDate) -> Result<f64, Error> {
+    if end <= start {
+        return Err(Error::InvalidDateRange);
+    }
+    let value = |date: &NaiveDate| index.data.iter().find(|v| v.date == *date ).and_then(|v| v.value )
+        .ok_or(Error::MissingObservation { series_code: index.name.clone(), date: *date, });
+    Ok(100.0 * (value(end)? / value(start)? - 1.0) * DAY_COUNT_BASIS / (*end - *start).num_days() as f64)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    #[test]
+    fn unit_test_sonia() {
+        use crate::{IADBSeries, sonia::{self, Convention}, parser::parse_response, utils::{CSVF, MissingValuePolicy}};
+        let body: &str = include_str!("../tests/fixtures/synthetic/iudsoia_tn.csv");
+        let data = parse_response(body, &CSVF::TN, &MissingValuePolicy::Skip, &[String::from("IUDSOIA")]).unwrap().remove(0).data;
+        let series: IADBSeries = IADBSeries { name: String::from("IUDSOIA"), description: String::new(), data, metadata: None, };
+        let d = |day: u32| NaiveDate::from_ymd_opt(2000, 1, day).unwrap();
+        let r = |day: u32| series.data.iter().find(|v| v.date == d(day) ).unwrap().value.unwrap() / 100.0;
+        let compound = |rates: &[(f64, f64)], days: f64| 100.0 * (rates.iter().map(|(r, n)| 1.0 + r * n / 365.0 ).product::<f64>() - 1.0) * 365.0 / days;
+        // Friday rate accrues over the weekend
+        let plain: f64 = sonia::compounded_rate(&series, &d(5), &d(10), &Convention::Plain).unwrap();
+        assert!((plain - compound(&[(r(5), 1.0), (r(6), 1.0), (r(7), 3.0)], 5.0)).abs() < 1e-12);
+        let lookback: f64 = sonia::compounded_rate(&series, &d(5), &d(10), &Convention::Lookback { days: 1 }).unwrap();
+        assert!((lookback - compound(&[(r(4), 1.0), (r(5), 1.0), (r(6), 3.0)], 5.0)).abs() < 1e-12);
+        let shift: f64 = sonia::compounded_rate(&series, &d(5), &d(10), &Convention::ObservationShift { days: 1 }).unwrap();
+        assert!((shift - compound(&[(r(4), 1.0), (r(5), 1.0), (r(6), 1.0)], 3.0)).abs() < 1e-12);
+        let average: f64 = sonia::simple_average(&series, &d(5), &d(10), &Convention::Plain).unwrap();
+        assert!((average - 100.0 * (r(5) + r(6) + 3.0 * r(7)) / 5.0).abs() < 1e-12);
+        assert!(sonia::compounded_rate(&series, &d(4), &d(10), &Convention::Lookback { days: 1 }).is_err());
+        assert!(sonia::compounded_rate(&series, &d(10), &d(5), &Convention::Plain).is_err());
+        // Period that runs past the last observation (Monday 10 Jan) does not reuse the last rate, except over a weekend
+        assert!(sonia::compounded_rate(&series, &d(5), &d(12), &Convention::Plain).is_err());
+        assert!(sonia::simple_average(&series, &d(5), &NaiveDate::from_ymd_opt(2000, 6, 30).unwrap(), &Convention::Plain).is_err());
+        assert!(sonia::compounded_rate(&series, &d(5), &d(12), &Convention::ObservationShift { days: 1 }).is_err());
+        assert!(sonia::compounded_rate(&series, &d(5), &d(11), &Convention::Lookback { days: 1 }).is_ok());
+        let friday: IADBSeries = series.between(&d(1), &d(7));
+        assert!(sonia::compounded_rate(&friday, &d(5), &d(10), &Convention::Plain).is_ok());
+        assert!(sonia::compounded_rate(&friday, &d(5), &d(11), &Convention::Plain).is_err());
+        // Compounded index reproduces the compounded rate
+        let index: IADBSeries = sonia::compounded_index(&series, &d(1), 100.0).unwrap();
+        assert_eq!(index.data[0].date, d(4));
+        assert_eq!(index.data.len(), 5);
+        assert!((sonia::rate_from_index(&index, &d(5), &d(10)).unwrap() - plain).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,139 @@
+//! HTTP transport that sends the requests of the `IADBClient`, which can be replaced by a custom implementation
+//! (e.g., a corporate HTTP stack, an HTTP client of a different async runtime, or an in-memory fake in tests).
+use std::{fmt, future::Future, pin::Pin};
+use reqwest::{Client, Response};
+use crate::error::Error;
+
+
+/// Future returned by `Transport::get`.
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<TransportResponse, Error>> + Send + 'a>>;
+
+
+/// Response of the HTTP transport.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransportResponse {
+    /// Status code of the response.
+    pub status: u16,
+    /// Headers of the response as name-value pairs.
+    pub headers: Vec<(String, String)>,
+    /// Body of the response.
+    pub body: Vec<u8>,
+}
+
+impl TransportResponse {
+
+    /// Value of the first header with the provided name (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(k, _)| k.eq_ignore_ascii_case(name) ).map(|(_, v)| v.as_str() )
+    }
+
+    /// Body of the response as text (Note: Invalid UTF-8 sequences are replaced).
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+
+/// HTTP transport that sends a GET request to a URL and returns the status, headers and body of the response.
+///
+/// Note: Failed connections should be reported as `Error::TransportError` (or `Error::ReqwestError`) so that they are retried
+/// according to the retry policy of the client, while non-2xx responses should be returned as responses rather than errors.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use iadb_api::{IADBClient, transport::{Transport, TransportFuture, TransportResponse}};
+///
+/// #[derive(Debug)]
+/// struct StaticTransport;
+///
+/// impl Transport for StaticTransport {
+///     fn get<'a>(&'a self, _url: &'a str) -> TransportFuture<'a> {
+///         Box::pin(async move {
+///             Ok(TransportResponse { status: 200, headers: vec![], body: b"DATE,IUDSOIA\n02 Jan 2024,5.19\n".to_vec(), })
+///         })
+///     }
+/// }
+///
+/// let client: IADBClient = IADBClient::builder().transport(Arc::new(StaticTransport)).build().unwrap();
+/// ```
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Sends a GET request to the URL.
+    fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a>;
+}
+
+
+/// Default transport that sends the requests with `reqwest`.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+
+    /// Creates a transport that sends the requests with the provided `reqwest` client.
+    pub fn new(client: Client) -> Self {
+        ReqwestTransport { client, }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let response: Response = self.client.get(url).send().await?;
+            let status: u16 = response.status().as_u16();
+            let headers: Vec<(String, String)> = response.headers().iter()
+                .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.to_string(), String::from(v)) ) )
+                .collect();
+            let body: Vec<u8> = response.bytes().await?.to_vec();
+            Ok(TransportResponse { status, headers, body, })
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use crate::transport::{Transport, TransportFuture, TransportResponse};
+
+    /// In-memory transport that answers every request with the same response and records the URLs.
+    #[derive(Debug)]
+    struct FakeTransport {
+        response: TransportResponse,
+        urls: Mutex<Vec<String>>,
+    }
+
+    impl Transport for FakeTransport {
+        fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+            self.urls.lock().unwrap().push(String::from(url));
+            Box::pin(async move { Ok(self.response.clone()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn unit_test_transport() {
+        use std::sync::Arc;
+        use chrono::NaiveDate;
+        use crate::{SeriesCode, IADBClient, RetryPolicy, schemas::IADBSeries};
+        let transport: Arc<FakeTransport> = Arc::new(FakeTransport {
+            response: TransportResponse {
+                status: 200, headers: vec![(String::from("Content-Type"), String::from("text/csv"))],
+                body: include_bytes!("../tests/fixtures/synthetic/iudsoia_tn.csv").to_vec(),
+            },
+            urls: Mutex::new(vec![]),
+        });
+        assert_eq!(transport.response.header("content-type"), Some("text/csv"));
+        let client: IADBClient = IADBClient::builder().base_url("http://iadb.test/").transport(transport.clone()).retry_policy(RetryPolicy::none()).build().unwrap();
+        let date_from: NaiveDate = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let date_to: NaiveDate = NaiveDate::from_ymd_opt(2018, 10, 1).unwrap();
+        let data: IADBSeries = client.get_data(&SeriesCode::IUDSOIA, &date_from, &date_to).await.unwrap();
+        assert_eq!(data.data.len(), 5);
+        let urls: Vec<String> = transport.urls.lock().unwrap().clone();
+        assert!(urls[0].starts_with("http://iadb.test/?csv.x=yes&SeriesCodes=IUDSOIA"));
+        // Search page goes through the same transport
+        let _ = client.search("gilt yields").await;
+        assert!(transport.urls.lock().unwrap()[1].ends_with("?SearchText=gilt+yields"));
+    }
+}
@@ -1,15 +1,39 @@
-use std::fmt;
-use reqwest::{Client, Response};
-use csv::{Reader, ReaderBuilder, StringRecord};
+use std::{fmt, future::Future};
+use chrono::NaiveDate;
 use crate::error::Error;
-use crate::{BASE_URL, schemas::{IADBSeries, IADBDataPoint}};
+use crate::{SeriesCode, is_valid_code, client::IADBClient, transport::TransportResponse, schemas::{IADBSeries, IADBSeriesMetadata}, parser::{ParsedSeries, parse_response}};
 
 
-#[derive(Debug)]
+/// Format of the dates in the request parameters (e.g., `01/Jan/2000`).
+pub const REQUEST_DATE_FORMAT: &str = "%d/%b/%Y";
+
+/// Format of the dates in the CSV response (e.g., `04 Jan 2000`).
+pub const RESPONSE_DATE_FORMAT: &str = "%d %b %Y";
+
+
+/// Messages in the IADB error pages that indicate an unknown series code.
+///
+/// Note: The messages have not been checked against captured IADB error pages, so unrecognised pages end up as `Error::UnexpectedResponse`.
+const UNKNOWN_SERIES_MESSAGES: [&str; 4] = ["invalid series code", "series code not found", "no series found", "cannot find series"];
+
+/// Messages in the IADB error pages that indicate an invalid date range.
+const INVALID_DATE_MESSAGES: [&str; 4] = ["invalid date", "date from must be", "datefrom must be", "no data for the date range"];
+
+/// Maximum number of characters of an unexpected response included in the error.
+pub(crate) const SNIPPET_LENGTH: usize = 200;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Layout of the CSV response.
 pub enum CSVF {
+    /// Tabular with titles: Titles section followed by a table with one column per series.
     TT,
+    /// Tabular without titles: Table with one column per series.
+    #[default]
     TN,
+    /// Columnar with titles: Titles section followed by a table with one row per series and date.
     CT,
+    /// Columnar without titles: Table with one row per series and date.
     CN,
 }
 
@@ -41,10 +65,77 @@ impl fmt::Display for VPD {
 }
 
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Policy of handling the observations whose values are empty or non-numeric (e.g., `n/a` or a footnote marker).
+pub enum MissingValuePolicy {
+    /// Non-numeric values fail the request with `Error::InvalidValue`, while empty values are left out.
+    ///
+    /// Note: Observations served from the cache that were stored as missing (e.g., by a request with `MissingValuePolicy::KeepAsMissing`)
+    /// fail the request as well, since the cache does not tell empty values from non-numeric ones.
+    Error,
+    /// Empty and non-numeric values are left out of the series.
+    #[default]
+    Skip,
+    /// Empty and non-numeric values are kept in the series as missing observations (i.e., with the value `None`).
+    KeepAsMissing,
+}
+
+
+#[derive(Debug, Clone)]
+/// Options of the request that control the format of the response.
+pub struct RequestOptions {
+    /// Layout of the CSV response (Default: `CSVF::TN`).
+    pub csv_format: CSVF,
+    /// Whether the series are labelled with their codes rather than their titles (Default: `true`).
+    pub using_codes: bool,
+    /// Whether the full descriptions and other metadata of the series are requested (Default: `false`).
+    ///
+    /// Note: Metadata is returned in the titles section, so `CSVF::TN` and `CSVF::CN` are replaced with `CSVF::TT` and `CSVF::CT`.
+    pub metadata: bool,
+    /// Policy of handling the empty and non-numeric values (Default: `MissingValuePolicy::Skip`).
+    pub missing_values: MissingValuePolicy,
+    /// Maximum number of days requested at once by `get_data` (Default: `None`).
+    ///
+    /// Note: Longer date ranges are split into consecutive windows of this many days, which are requested separately
+    /// and stitched into a single series (e.g., to avoid timeouts when requesting decades of daily data).
+    pub chunk_days: Option<u64>,
+    /// Maximum number of windows of a chunked date range that are requested at the same time (Default: `1`).
+    pub chunk_concurrency: usize,
+}
+
+impl RequestOptions {
+
+    /// Layout of the CSV response that will be requested.
+    pub fn layout(&self) -> CSVF {
+        match self.csv_format {
+            CSVF::TN if self.metadata => CSVF::TT,
+            CSVF::CN if self.metadata => CSVF::CT,
+            layout => layout,
+        }
+    }
+
+    /// Value of the `UsingCodes` parameter.
+    pub(crate) fn using_codes_param(&self) -> String {
+        String::from(if self.using_codes { "Y" } else { "N" })
+    }
+
+    /// Value of the `VFD` parameter.
+    pub(crate) fn vfd_param(&self) -> String {
+        String::from(if self.metadata { "Y" } else { "N" })
+    }
+}
+
+impl Default for RequestOptions {
+    fn default() -> Self {
+        RequestOptions { csv_format: CSVF::TN, using_codes: true, metadata: false, missing_values: MissingValuePolicy::Skip, chunk_days: None, chunk_concurrency: 1, }
+    }
+}
+
+
 #[derive(Debug)]
 pub enum Param<'a> {
-    DateFrom { v: &'a String },
-    DateTo { v: &'a String },
+    DateFrom { v: &'a NaiveDate },
+    DateTo { v: &'a NaiveDate },
     CSVF { v: &'a CSVF },
     UsingCodes { v: &'a String },
     VPD { v: &'a VPD },
@@ -52,13 +143,13 @@ pub enum Param<'a> {
 }
 
 impl<'a> Param<'a> {
-    fn add_param_to_url(&self, url: &mut String) -> () {
+    fn add_param_to_url(&self, url: &mut String) {
         let url_param: String = match self {
-            Param::DateFrom { v } => format!("&Datefrom={}", v),
-            Param::DateTo { v } => format!("&Dateto={}", v),
-            Param::CSVF { v } => format!("&CSVF={}", v.to_string()),
+            Param::DateFrom { v } => format!("&Datefrom={}", v.format(REQUEST_DATE_FORMAT)),
+            Param::DateTo { v } => format!("&Dateto={}", v.format(REQUEST_DATE_FORMAT)),
+            Param::CSVF { v } => format!("&CSVF={}", v),
             Param::UsingCodes { v } => format!("&UsingCodes={}", v),
-            Param::VPD { v } => format!("&VPD={}", v.to_string()),
+            Param::VPD { v } => format!("&VPD={}", v),
             Param::VFD { v } => format!("&VFD={}", v),
         };
         url.push_str(&url_param);
@@ -66,53 +157,188 @@ impl<'a> Param<'a> {
 }
 
 
+/// Finds the layout of the CSV response in the request parameters (Default: `CSVF::TN`).
+fn find_layout(params: &[Param]) -> CSVF {
+    params.iter().find_map(|p| match p { Param::CSVF { v } => Some(**v), _ => None, } ).unwrap_or_default()
+}
+
+
+/// Description of the series, falling back to the description in the titles section of the response.
+fn series_description(description: String, series: &ParsedSeries) -> String {
+    match series.titles.get("DESCRIPTION") {
+        Some(v) if description.is_empty() => v.clone(),
+        _ => description,
+    }
+}
+
+
+/// Rejects the series codes that cannot be IADB series codes, so that they are not injected into the query of the URL.
+fn check_series_codes(series_codes: &[String]) -> Result<(), Error> {
+    match series_codes.iter().find(|v| !is_valid_code(v) ) {
+        Some(v) => Err(Error::InvalidSeriesCode(v.clone())),
+        None => Ok(()),
+    }
+}
+
+
+/// Constructs a URL for API request from the comma-separated list of series codes and the request parameters.
+fn build_url(base_url: &str, series_codes: &String, params: Vec<Param>, additional_params: Option<String>) -> String {
+    let mut url: String = String::from(base_url);
+    // Add parameters to the URL
+    url.push_str(&format!("?csv.x=yes&SeriesCodes={}", series_codes));
+    for param in params {
+        param.add_param_to_url(&mut url);
+    }
+    // Add additional parameters to the URL
+    if let Some(v) = additional_params {
+        url.push_str(&v);
+    }
+    url
+}
+
+
 /// Make a request to the provided URL, validate the status code of the response, and return deserialized data.
-async fn process_request(url: String) -> Result<Vec<IADBDataPoint>, Error> {
-    let user_agent: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/54.0.2840.90 Safari/537.36";
-    let client: Client = Client::builder().user_agent(user_agent).build()?;
-    let response: Response = client.get(url).send().await?;
-    let response_body: String = response.text().await?;
-    // Deserialize the CSV
-    let mut rdr: Reader<&[u8]> = ReaderBuilder::new().has_headers(true).from_reader(response_body.as_bytes());
-    // Replace default CSV headers
-    let _: &StringRecord = rdr.headers()?;
-    rdr.set_headers(StringRecord::from(vec!["date", "value"]));
-    // Deserialize data
-    let mut data: Vec<IADBDataPoint> = Vec::<IADBDataPoint>::new();
-    for entry in rdr.deserialize() {
-        let entry: IADBDataPoint = entry?;
-        data.push(entry);
-    }
-    Ok(data)
+///
+/// Note: The response is deserialized according to its layout, which is one of the layouts defined by `CSVF`.
+/// The returned vector contains one series per requested series code, in the order of `series_codes`.
+/// Failed requests are sent again according to the retry policy of the client.
+async fn process_request(client: &IADBClient, url: String, layout: &CSVF, missing_values: &MissingValuePolicy, series_codes: &[String]) -> Result<Vec<ParsedSeries>, Error> {
+    with_retries(client, || send_request(client, &url, layout, missing_values, series_codes) ).await
+}
+
+
+/// Runs the request until it succeeds or the retry policy of the client gives up, waiting for the rate limiter before every attempt.
+pub(crate) async fn with_retries<T, F, Fut>(client: &IADBClient, request: F) -> Result<T, Error>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let mut attempt: u32 = 1;
+    loop {
+        if let Some(rate_limiter) = client.rate_limiter() {
+            rate_limiter.acquire().await;
+        }
+        match request().await {
+            Err(e) if client.retry_policy().should_retry(&e, attempt) => {
+                tokio::time::sleep(client.retry_policy().backoff(attempt)).await;
+                attempt += 1;
+            },
+            result => return result,
+        }
+    }
+}
+
+
+/// Sends a single request to the provided URL and deserializes the response.
+async fn send_request(client: &IADBClient, url: &str, layout: &CSVF, missing_values: &MissingValuePolicy, series_codes: &[String]) -> Result<Vec<ParsedSeries>, Error> {
+    let response: TransportResponse = client.transport().get(url).await?;
+    let response_body: String = response.text();
+    validate_response(response.status, response.header("Content-Type"), &response_body, series_codes)?;
+    parse_response(&response_body, layout, missing_values, series_codes)
+}
+
+
+/// Checks that the response contains CSV data rather than an error page.
+///
+/// Note: The IADB responds to invalid requests with an HTML page and status code `200`,
+/// so the body of HTML responses is searched for the known IADB error messages.
+fn validate_response(status: u16, content_type: Option<&str>, body: &str, series_codes: &[String]) -> Result<(), Error> {
+    if !(200..300).contains(&status) {
+        return Err(Error::HttpStatus(status));
+    }
+    let is_html: bool = match content_type {
+        Some(v) if v.to_lowercase().contains("html") => true,
+        _ => body.trim_start().starts_with('<'),
+    };
+    if !is_html {
+        return Ok(());
+    }
+    let text: String = html_to_text(body);
+    let lowercase_text: String = text.to_lowercase();
+    if UNKNOWN_SERIES_MESSAGES.iter().any(|m| lowercase_text.contains(m) ) {
+        return Err(Error::UnknownSeries(series_codes.join(",")));
+    }
+    if INVALID_DATE_MESSAGES.iter().any(|m| lowercase_text.contains(m) ) {
+        return Err(Error::InvalidDateRange);
+    }
+    Err(Error::UnexpectedResponse { snippet: text.chars().take(SNIPPET_LENGTH).collect() })
+}
+
+
+/// Strips the tags, scripts and styles from an HTML page and collapses the whitespace of the remaining text.
+pub(crate) fn html_to_text(html: &str) -> String {
+    let mut text: String = String::new();
+    let mut rest: &str = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        text.push(' ');
+        let tag: &str = &rest[start..];
+        // Skip the contents of scripts and styles
+        let closing_tag: Option<&str> = ["script", "style"].into_iter()
+            .find(|t| tag.get(1..(t.len() + 1)).is_some_and(|v| v.eq_ignore_ascii_case(t) ) );
+        let end: Option<usize> = match closing_tag {
+            Some(t) => tag.to_ascii_lowercase().find(&format!("</{}", t)).and_then(|i| tag[i..].find('>').map(|j| i + j ) ),
+            None => tag.find('>'),
+        };
+        match end {
+            Some(end) => rest = &tag[(end + 1)..],
+            None => { rest = ""; },
+        }
+    }
+    text.push_str(rest);
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
 }
 
 
 /// Constructs a URL for API request, sends the request, and returns the deserialzied response.
 ///
 /// # Input
+/// - `client`: Client that sends the request
 /// - `series_code`: Code of the time series in the IADB.
 /// - `params`: List of parameters expected by the IADB API endpoint
+/// - `missing_values`: Policy of handling the empty and non-numeric values
 /// - `additional_params`: Additional parameters to add to the request
-pub async fn call_api_endpoint<'a>(series_code: &String, description: &Option<String>, params: Vec<Param<'a>>, additional_params: Option<String>) -> Result<IADBSeries, Error> {
-    // Set up a URL for the API endpoint
-    let mut url: String = String::from(BASE_URL);
-    // Add parameters to the URL
-    url.push_str(&format!("?csv.x=yes&SeriesCodes={}", series_code));
-    for param in params {
-        param.add_param_to_url(&mut url);
-    }
-    // Add additional parameters to the URL
-    match additional_params {
-        Some(v) => url.push_str(&v),
-        None => (),
+///
+/// Note: Series codes that are not strings of ASCII letters and digits are rejected with `Error::InvalidSeriesCode` before any request is sent.
+pub async fn call_api_endpoint<'a>(client: &IADBClient, series_code: &String, description: &Option<String>, params: Vec<Param<'a>>, missing_values: &MissingValuePolicy, additional_params: Option<String>) -> Result<IADBSeries, Error> {
+    check_series_codes(std::slice::from_ref(series_code))?;
+    let layout: CSVF = find_layout(&params);
+    let url: String = build_url(client.base_url(), series_code, params, additional_params);
+    // Process API response
+    let series: ParsedSeries = process_request(client, url, &layout, missing_values, std::slice::from_ref(series_code)).await?.remove(0);
+    let description: String = series_description(description.clone().unwrap_or_default(), &series);
+    let metadata: Option<IADBSeriesMetadata> = series.metadata();
+    Ok(IADBSeries { name: series_code.to_string(), description, data: series.data, metadata, })
+}
+
+
+/// Constructs a URL for API request with multiple series codes, sends a single request, and returns one deserialized series per code.
+///
+/// # Input
+/// - `client`: Client that sends the request
+/// - `series_codes`: Codes of the time series in the IADB.
+/// - `params`: List of parameters expected by the IADB API endpoint
+/// - `missing_values`: Policy of handling the empty and non-numeric values
+/// - `additional_params`: Additional parameters to add to the request
+///
+/// Note: No request is sent if `series_codes` is empty, and an empty vector is returned.
+/// Series codes that are not strings of ASCII letters and digits are rejected with `Error::InvalidSeriesCode` before any request is sent.
+pub async fn call_api_endpoint_many<'a>(client: &IADBClient, series_codes: &[SeriesCode], params: Vec<Param<'a>>, missing_values: &MissingValuePolicy, additional_params: Option<String>) -> Result<Vec<IADBSeries>, Error> {
+    if series_codes.is_empty() {
+        return Ok(vec![]);
     }
+    let layout: CSVF = find_layout(&params);
+    let names: Vec<String> = series_codes.iter().map(|v| v.to_string() ).collect();
+    check_series_codes(&names)?;
+    let url: String = build_url(client.base_url(), &names.join(","), params, additional_params);
     // Process API response
-    let description: String = match description {
-        Some(v) => v.clone(),
-        None => String::from(""),
-    };
-    let data: Vec<IADBDataPoint> = process_request(url).await?;
-    Ok(IADBSeries { name: series_code.to_string(), description: description, data, })
+    let parsed_series: Vec<ParsedSeries> = process_request(client, url, &layout, missing_values, &names).await?;
+    let series: Vec<IADBSeries> = series_codes.iter().zip(names).zip(parsed_series)
+        .map(|((series_code, name), series)| IADBSeries {
+            name, description: series_description(series_code.description(), &series), metadata: series.metadata(), data: series.data,
+        })
+        .collect();
+    Ok(series)
 }
 
 
@@ -121,14 +347,50 @@ mod tests {
     use tokio;
 
     #[tokio::test]
-    async fn unit_test_request() -> () {
+    async fn unit_test_request() {
         use reqwest::{Client, Response};
-        let user_agent: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/54.0.2840.90 Safari/537.36";
-        let client: Client = Client::builder().user_agent(user_agent).build().unwrap();
+        use crate::{client::DEFAULT_USER_AGENT, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        server.route("SeriesCodes=IUMBV34,IUMBV37", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iumbv34_iumbv37_tt.csv"))]);
+        let client: Client = Client::builder().user_agent(DEFAULT_USER_AGENT).build().unwrap();
         // Request
-        let url: String = String::from("http://www.bankofengland.co.uk/boeapps/iadb/fromshowcolumns.asp?csv.x=yes&Datefrom=01/Jan/2000&Dateto=01/Oct/2018&SeriesCodes=IUMBV34,IUMBV37,IUMBV42,IUMBV45&CSVF=TT&UsingCodes=Y&VPD=Y&VFD=N");
+        let url: String = format!("{}?csv.x=yes&Datefrom=01/Jan/2000&Dateto=01/Oct/2018&SeriesCodes=IUMBV34,IUMBV37&CSVF=TT&UsingCodes=Y&VPD=Y&VFD=N", server.url());
         let response: Response = client.get(url).send().await.unwrap();
         let response_body: String = response.text().await.unwrap();
-        println!("{}", response_body)
+        println!("{}", response_body);
+        assert!(response_body.contains("DATE,IUMBV34,IUMBV37"));
+    }
+
+    #[test]
+    fn unit_test_build_url() {
+        use chrono::NaiveDate;
+        use crate::{BASE_URL, utils::{Param, build_url}};
+        let date_from: NaiveDate = NaiveDate::from_ymd_opt(2020, 9, 1).unwrap();
+        let url: String = build_url(BASE_URL, &String::from("IUDSOIA"), vec![Param::DateFrom { v: &date_from }], None);
+        assert!(url.ends_with("?csv.x=yes&SeriesCodes=IUDSOIA&Datefrom=01/Sep/2020"));
+    }
+
+    #[test]
+    fn unit_test_validate_response() {
+        use crate::{error::Error, utils::validate_response};
+        let series_codes: Vec<String> = vec![String::from("IUDXXXX")];
+        let csv: &str = "DATE,IUDSOIA\n04 Jan 2000,5.2\n";
+        assert!(validate_response(200, Some("text/csv"), csv, &series_codes).is_ok());
+        assert!(matches!(validate_response(503, Some("text/html"), "", &series_codes), Err(Error::HttpStatus(503))));
+        let html: &str = "<html><head><style>p { color: red; }</style></head><body><p>Invalid series code: IUDXXXX</p></body></html>";
+        assert!(matches!(validate_response(200, Some("text/html"), html, &series_codes), Err(Error::UnknownSeries(_))));
+        let html: &str = "<html><body><p>Date From must be before Date To</p></body></html>";
+        assert!(matches!(validate_response(200, None, html, &series_codes), Err(Error::InvalidDateRange)));
+        let html: &str = "<html><body><h1>Service   unavailable</h1></body></html>";
+        match validate_response(200, Some("text/html; charset=utf-8"), html, &series_codes) {
+            Err(Error::UnexpectedResponse { snippet }) => assert_eq!(snippet, "Service unavailable"),
+            _ => panic!("Expected an unexpected response error"),
+        }
+        // Characters whose lowercase has a different length do not shift the end of the script
+        let html: &str = "<html><script>var s = 'ẞ';</script><body><p>Service unavailable</p></body></html>";
+        match validate_response(200, Some("text/html"), html, &series_codes) {
+            Err(Error::UnexpectedResponse { snippet }) => assert_eq!(snippet, "Service unavailable"),
+            _ => panic!("Expected an unexpected response error"),
+        }
     }
 }
\ No newline at end of file
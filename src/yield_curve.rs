@@ -0,0 +1,460 @@
+use std::{fmt, collections::BTreeMap};
+use serde::{Serialize, Deserialize};
+use chrono::{NaiveDate, Days};
+use crate::error::Error;
+use crate::{SeriesCode, client::IADBClient, schemas::IADBSeries};
+
+
+/// Tenors (in years) of the British Government Securities yield series in the IADB.
+pub const TENORS: [f64; 3] = [5.0, 10.0, 20.0];
+
+/// Number of days before the requested date that are searched for the latest curve (e.g., over weekends and bank holidays).
+const LOOKBACK_DAYS: u64 = 14;
+
+/// Number of steps of the numerical integration of the forward curve.
+const INTEGRATION_STEPS: usize = 1000;
+
+/// Time (in years) between two coupons of a gilt.
+const COUPON_PERIOD: f64 = 0.5;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Representation of the yields in a curve.
+///
+/// Note: Zero coupon and instantaneous forward rates are continuously compounded, while par yields are the coupon rates
+/// of bonds with semi-annual coupons that are priced at par.
+pub enum CurveType {
+    /// Nominal par yields (`IUDSNPY`, `IUDMNPY`, `IUDLNPY`).
+    Par,
+    /// Nominal zero coupon yields (`IUDSIZC`, `IUDMIZC`, `IUDLIZC`).
+    Zero,
+    /// Nominal implied (instantaneous) forward yields (`IUDSIIF`, `IUDMIIF`, `IUDLIIF`).
+    Forward,
+}
+
+impl CurveType {
+
+    /// Series codes of the curve, in the order of `TENORS`.
+    pub fn series_codes(&self) -> [SeriesCode; 3] {
+        match self {
+            CurveType::Par => [SeriesCode::IUDSNPY, SeriesCode::IUDMNPY, SeriesCode::IUDLNPY],
+            CurveType::Zero => [SeriesCode::IUDSIZC, SeriesCode::IUDMIZC, SeriesCode::IUDLIZC],
+            CurveType::Forward => [SeriesCode::IUDSIIF, SeriesCode::IUDMIIF, SeriesCode::IUDLIIF],
+        }
+    }
+}
+
+impl fmt::Display for CurveType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurveType::Par => write!(f, "Par"),
+            CurveType::Zero => write!(f, "Zero"),
+            CurveType::Forward => write!(f, "Forward"),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+/// Method of interpolating the yields between the tenors of a curve.
+///
+/// Note: Yields before the first tenor and after the last tenor are extrapolated flat.
+pub enum Interpolation {
+    /// Straight line between the neighbouring tenors.
+    #[default]
+    Linear,
+    /// Monotone cubic Hermite spline (Fritsch-Carlson), which is smooth and does not overshoot between the tenors.
+    MonotoneCubic,
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Yield at a single tenor of the curve.
+pub struct YieldPoint {
+    /// Time to maturity, in years.
+    pub tenor: f64,
+    /// Yield, in percent.
+    pub rate: f64,
+}
+
+
+/// Yield curve of British Government Securities on a single date.
+///
+/// Note: Conversions between the representations (e.g., `to_par`) keep only the yields at the tenors of the curve,
+/// so converting a curve back reproduces the original yields only up to the interpolation error between the tenors.
+///
+/// # Examples
+///
+/// ```rust
+/// use iadb_api::{NaiveDate, yield_curve::{YieldCurve, CurveType, Interpolation}};
+///
+/// let date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 4).unwrap();
+/// let curve: YieldCurve = YieldCurve::new(date, CurveType::Zero, &[(5.0, 3.6), (10.0, 3.7), (20.0, 4.2)]).unwrap()
+///     .interpolation(Interpolation::MonotoneCubic);
+///
+/// assert!(3.6 < curve.rate(7.0) && curve.rate(7.0) < 3.7);
+/// assert!(99.0 < curve.bond_price(curve.to_par().rate(10.0), 10.0) && curve.bond_price(curve.to_par().rate(10.0), 10.0) < 101.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct YieldCurve {
+    date: NaiveDate,
+    curve_type: CurveType,
+    interpolation: Interpolation,
+    points: Vec<YieldPoint>,
+    interpolant: Interpolant,
+}
+
+impl YieldCurve {
+
+    /// Creates a curve from the `(tenor, yield)` pairs, with tenors in years and yields in percent.
+    ///
+    /// # Input
+    /// - `date`: Date of the curve
+    /// - `curve_type`: Representation of the yields
+    /// - `points`: Yields at the tenors of the curve (Note: Tenors must be positive and distinct, but do not need to be sorted)
+    pub fn new(date: NaiveDate, curve_type: CurveType, points: &[(f64, f64)]) -> Result<Self, Error> {
+        let mut points: Vec<YieldPoint> = points.iter().map(|(tenor, rate)| YieldPoint { tenor: *tenor, rate: *rate, } ).collect();
+        if points.is_empty() {
+            return Err(Error::InvalidCurve(String::from("The curve has no points")));
+        }
+        if points.iter().any(|v| !v.tenor.is_finite() || v.tenor <= 0.0 || !v.rate.is_finite() ) {
+            return Err(Error::InvalidCurve(String::from("Tenors must be positive and yields must be finite")));
+        }
+        points.sort_by(|a, b| a.tenor.total_cmp(&b.tenor) );
+        if points.windows(2).any(|w| w[0].tenor == w[1].tenor ) {
+            return Err(Error::InvalidCurve(String::from("Tenors must be distinct")));
+        }
+        let interpolant: Interpolant = Interpolant::new(&points, Interpolation::default());
+        Ok(YieldCurve { date, curve_type, interpolation: Interpolation::default(), points, interpolant, })
+    }
+
+    /// Sets the method of interpolating the yields between the tenors (Default: `Interpolation::Linear`).
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self.interpolant = Interpolant::new(&self.points, interpolation);
+        self
+    }
+
+    /// Date of the curve.
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Representation of the yields.
+    pub fn curve_type(&self) -> CurveType {
+        self.curve_type
+    }
+
+    /// Yields at the tenors of the curve, sorted by tenor.
+    pub fn points(&self) -> &[YieldPoint] {
+        &self.points
+    }
+
+    /// Yield (in percent) at the tenor (in years), interpolated between the tenors of the curve.
+    pub fn rate(&self, tenor: f64) -> f64 {
+        self.interpolant.value(tenor)
+    }
+
+    /// Continuously compounded zero coupon yield (in percent) at the tenor (in years).
+    pub fn zero_rate(&self, tenor: f64) -> f64 {
+        match self.curve_type {
+            CurveType::Zero => self.rate(tenor),
+            CurveType::Forward => self.integrate(tenor) / tenor.max(f64::EPSILON),
+            CurveType::Par => self.bootstrap_zero_rate(tenor),
+        }
+    }
+
+    /// Instantaneous forward yield (in percent) at the tenor (in years).
+    pub fn forward_rate(&self, tenor: f64) -> f64 {
+        match self.curve_type {
+            CurveType::Forward => self.rate(tenor),
+            // f(t) = z(t) + t * z'(t)
+            CurveType::Zero => self.rate(tenor) + tenor * self.interpolant.derivative(tenor),
+            CurveType::Par => self.to_zero().forward_rate(tenor),
+        }
+    }
+
+    /// Par yield (in percent) of a bond with semi-annual coupons that matures at the tenor (in years).
+    pub fn par_rate(&self, tenor: f64) -> f64 {
+        if self.curve_type == CurveType::Par {
+            return self.rate(tenor);
+        }
+        let annuity: f64 = coupon_schedule(tenor).iter().map(|(t, accrual)| accrual * self.discount_factor(*t) ).sum();
+        100.0 * (1.0 - self.discount_factor(tenor)) / annuity
+    }
+
+    /// Discount factor at the tenor (in years).
+    pub fn discount_factor(&self, tenor: f64) -> f64 {
+        (-self.zero_rate(tenor) / 100.0 * tenor).exp()
+    }
+
+    /// Price per 100 nominal of a bond with semi-annual coupons, discounted off the curve (Note: The first coupon period is shortened
+    /// if the maturity is not a whole number of coupon periods, and accrued interest is ignored).
+    ///
+    /// # Input
+    /// - `coupon`: Annual coupon rate, in percent
+    /// - `maturity`: Time to maturity, in years
+    pub fn bond_price(&self, coupon: f64, maturity: f64) -> f64 {
+        let coupons: f64 = coupon_schedule(maturity).iter().map(|(t, accrual)| coupon * accrual * self.discount_factor(*t) ).sum();
+        coupons + 100.0 * self.discount_factor(maturity)
+    }
+
+    /// Converts the curve to zero coupon yields at the same tenors.
+    pub fn to_zero(&self) -> YieldCurve {
+        self.convert(CurveType::Zero, |t| self.zero_rate(t) )
+    }
+
+    /// Converts the curve to par yields at the same tenors.
+    pub fn to_par(&self) -> YieldCurve {
+        self.convert(CurveType::Par, |t| self.par_rate(t) )
+    }
+
+    /// Converts the curve to instantaneous forward yields at the same tenors.
+    pub fn to_forward(&self) -> YieldCurve {
+        self.convert(CurveType::Forward, |t| self.forward_rate(t) )
+    }
+
+    /// Creates a curve of the given type with the yields at the tenors of this curve.
+    fn convert<F: Fn(f64) -> f64>(&self, curve_type: CurveType, rate: F) -> YieldCurve {
+        let points: Vec<YieldPoint> = self.points.iter().map(|v| YieldPoint { tenor: v.tenor, rate: rate(v.tenor), } ).collect();
+        let interpolant: Interpolant = Interpolant::new(&points, self.interpolation);
+        YieldCurve { date: self.date, curve_type, interpolation: self.interpolation, points, interpolant, }
+    }
+
+    /// Integral of the (forward) curve from `0` to the tenor, using Simpson's rule.
+    fn integrate(&self, tenor: f64) -> f64 {
+        let step: f64 = tenor / INTEGRATION_STEPS as f64;
+        let sum: f64 = (0..=INTEGRATION_STEPS).map(|i| {
+            let weight: f64 = if i == 0 || i == INTEGRATION_STEPS { 1.0 } else if i % 2 == 1 { 4.0 } else { 2.0 };
+            weight * self.rate(i as f64 * step)
+        }).sum();
+        sum * step / 3.0
+    }
+
+    /// Zero coupon yield bootstrapped from the par yields at the coupon dates, interpolated linearly between the coupon dates.
+    fn bootstrap_zero_rate(&self, tenor: f64) -> f64 {
+        // Discount factors at the coupon dates: DF(n) = (1 - c(n) / 2 * (DF(1) + ... + DF(n - 1))) / (1 + c(n) / 2)
+        let periods: usize = ((tenor / COUPON_PERIOD).ceil() as usize).max(1);
+        let mut zero_rates: Vec<(f64, f64)> = Vec::with_capacity(periods);
+        let mut annuity: f64 = 0.0;
+        for n in 1..=periods {
+            let t: f64 = n as f64 * COUPON_PERIOD;
+            let coupon: f64 = self.rate(t) / 100.0 * COUPON_PERIOD;
+            let discount_factor: f64 = (1.0 - coupon * annuity) / (1.0 + coupon);
+            annuity += discount_factor;
+            zero_rates.push((t, -100.0 * discount_factor.ln() / t));
+        }
+        match zero_rates.iter().position(|(t, _)| tenor <= *t ) {
+            Some(0) | None => zero_rates[0].1,
+            Some(i) => {
+                let ((t0, z0), (t1, z1)) = (zero_rates[i - 1], zero_rates[i]);
+                z0 + (z1 - z0) * (tenor - t0) / (t1 - t0)
+            },
+        }
+    }
+
+    /// Creates one curve per date on which all series of the curve have an observation.
+    ///
+    /// # Input
+    /// - `curve_type`: Representation of the yields
+    /// - `series`: Series of the curve (i.e., the series of `CurveType::series_codes`), in any order
+    pub fn from_series(curve_type: CurveType, series: &[IADBSeries]) -> Result<Vec<YieldCurve>, Error> {
+        let mut rates: BTreeMap<NaiveDate, Vec<(f64, f64)>> = BTreeMap::new();
+        for (tenor, series_code) in TENORS.iter().zip(curve_type.series_codes()) {
+            let series_code: String = series_code.to_string();
+            let series: &IADBSeries = series.iter().find(|v| v.name == series_code ).ok_or(Error::MissingColumn { series_code, })?;
+            for point in series.data.iter() {
+                if let Some(rate) = point.value {
+                    rates.entry(point.date).or_default().push((*tenor, rate));
+                }
+            }
+        }
+        rates.into_iter()
+            .filter(|(_, points)| points.len() == TENORS.len() )
+            .map(|(date, points)| YieldCurve::new(date, curve_type, &points) )
+            .collect()
+    }
+
+    /// Fetches the series of the curve from the IADB and creates the latest curve on or before the date.
+    ///
+    /// Note: The date of the returned curve is the latest date on which all series of the curve have an observation,
+    /// which is earlier than the requested date on weekends and bank holidays.
+    ///
+    /// # Input
+    /// - `client`: Client that sends the request
+    /// - `curve_type`: Representation of the yields
+    /// - `date`: Date of the curve
+    pub async fn fetch(client: &IADBClient, curve_type: CurveType, date: &NaiveDate) -> Result<YieldCurve, Error> {
+        let date_from: NaiveDate = date.checked_sub_days(Days::new(LOOKBACK_DAYS)).unwrap_or(*date);
+        YieldCurve::fetch_range(client, curve_type, &date_from, date).await?.pop()
+            .ok_or(Error::MissingObservation { series_code: curve_type.series_codes().map(|v| v.to_string() ).join(","), date: *date, })
+    }
+
+    /// Fetches the series of the curve from the IADB and creates one curve per date in the range.
+    ///
+    /// # Input
+    /// - `client`: Client that sends the request
+    /// - `curve_type`: Representation of the yields
+    /// - `date_from`: Date from which the curves will be created
+    /// - `date_to`: Date up to which the curves will be created
+    pub async fn fetch_range(client: &IADBClient, curve_type: CurveType, date_from: &NaiveDate, date_to: &NaiveDate) -> Result<Vec<YieldCurve>, Error> {
+        let series: Vec<IADBSeries> = client.get_many(&curve_type.series_codes(), date_from, date_to).await?;
+        YieldCurve::from_series(curve_type, &series)
+    }
+}
+
+impl fmt::Display for YieldCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Yield Curve: {} ({})", self.curve_type, self.date)?;
+        for point in self.points.iter() {
+            writeln!(f, "{} year: {}", point.tenor, point.rate)?;
+        }
+        Ok(())
+    }
+}
+
+
+/// Coupon dates (in years) and accrual periods of a bond with semi-annual coupons, counted back from the maturity.
+fn coupon_schedule(maturity: f64) -> Vec<(f64, f64)> {
+    let mut schedule: Vec<(f64, f64)> = Vec::new();
+    let mut t: f64 = maturity;
+    while 1e-9 < t {
+        schedule.push((t, t.min(COUPON_PERIOD)));
+        t -= COUPON_PERIOD;
+    }
+    schedule.reverse();
+    schedule
+}
+
+
+/// Piecewise interpolant through the points of a curve, with flat extrapolation.
+#[derive(Debug, Clone)]
+struct Interpolant {
+    interpolation: Interpolation,
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    /// Slopes at the points (Note: Only used by the cubic interpolation).
+    slopes: Vec<f64>,
+}
+
+impl Interpolant {
+
+    fn new(points: &[YieldPoint], interpolation: Interpolation) -> Self {
+        let xs: Vec<f64> = points.iter().map(|v| v.tenor ).collect();
+        let ys: Vec<f64> = points.iter().map(|v| v.rate ).collect();
+        let n: usize = xs.len();
+        let secants: Vec<f64> = (1..n).map(|i| (ys[i] - ys[i - 1]) / (xs[i] - xs[i - 1]) ).collect();
+        let slopes: Vec<f64> = (0..n).map(|i| {
+            if n == 1 {
+                return 0.0;
+            }
+            if i == 0 || i == n - 1 {
+                return secants[i.min(n - 2)];
+            }
+            let (d0, d1) = (secants[i - 1], secants[i]);
+            if d0 * d1 <= 0.0 {
+                return 0.0;
+            }
+            // Weighted harmonic mean of the secants keeps the spline monotone
+            let (h0, h1) = (xs[i] - xs[i - 1], xs[i + 1] - xs[i]);
+            3.0 * (h0 + h1) / ((2.0 * h1 + h0) / d0 + (h1 + 2.0 * h0) / d1)
+        }).collect();
+        Interpolant { interpolation, xs, ys, slopes, }
+    }
+
+    /// Index of the segment that contains `x` (Note: `x` must be within the range of the points).
+    fn segment(&self, x: f64) -> usize {
+        self.xs.partition_point(|v| *v <= x ).saturating_sub(1).min(self.xs.len() - 2)
+    }
+
+    fn value(&self, x: f64) -> f64 {
+        let n: usize = self.xs.len();
+        if x <= self.xs[0] {
+            return self.ys[0];
+        }
+        if self.xs[n - 1] <= x {
+            return self.ys[n - 1];
+        }
+        let i: usize = self.segment(x);
+        let h: f64 = self.xs[i + 1] - self.xs[i];
+        let s: f64 = (x - self.xs[i]) / h;
+        match self.interpolation {
+            Interpolation::Linear => self.ys[i] + (self.ys[i + 1] - self.ys[i]) * s,
+            Interpolation::MonotoneCubic => {
+                let (s2, s3) = (s * s, s * s * s);
+                (2.0 * s3 - 3.0 * s2 + 1.0) * self.ys[i] + (s3 - 2.0 * s2 + s) * h * self.slopes[i]
+                    + (-2.0 * s3 + 3.0 * s2) * self.ys[i + 1] + (s3 - s2) * h * self.slopes[i + 1]
+            },
+        }
+    }
+
+    fn derivative(&self, x: f64) -> f64 {
+        let n: usize = self.xs.len();
+        if n == 1 || x < self.xs[0] || self.xs[n - 1] < x {
+            return 0.0;
+        }
+        let i: usize = self.segment(x);
+        let h: f64 = self.xs[i + 1] - self.xs[i];
+        let s: f64 = (x - self.xs[i]) / h;
+        match self.interpolation {
+            Interpolation::Linear => (self.ys[i + 1] - self.ys[i]) / h,
+            Interpolation::MonotoneCubic => {
+                let s2: f64 = s * s;
+                ((6.0 * s2 - 6.0 * s) * self.ys[i] + (3.0 * s2 - 4.0 * s + 1.0) * h * self.slopes[i]
+                    + (-6.0 * s2 + 6.0 * s) * self.ys[i + 1] + (3.0 * s2 - 2.0 * s) * h * self.slopes[i + 1]) / h
+            },
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    #[test]
+    fn unit_test_yield_curve() {
+        use crate::yield_curve::{YieldCurve, CurveType, Interpolation};
+        let date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 4).unwrap();
+        // Interpolation
+        let curve: YieldCurve = YieldCurve::new(date, CurveType::Zero, &[(20.0, 4.2), (5.0, 3.6), (10.0, 3.7)]).unwrap();
+        assert!((curve.rate(7.5) - 3.65).abs() < 1e-12);
+        assert_eq!(curve.rate(1.0), 3.6);
+        assert_eq!(curve.rate(30.0), 4.2);
+        let cubic: YieldCurve = curve.clone().interpolation(Interpolation::MonotoneCubic);
+        assert!(cubic.rate(10.0) < cubic.rate(12.0) && cubic.rate(12.0) < cubic.rate(15.0) && cubic.rate(15.0) < 4.2);
+        // Flat continuously compounded curve
+        let flat: YieldCurve = YieldCurve::new(date, CurveType::Zero, &[(5.0, 4.0), (10.0, 4.0)]).unwrap();
+        assert!((flat.forward_rate(7.0) - 4.0).abs() < 1e-12);
+        assert!((flat.par_rate(10.0) - 200.0 * ((0.02f64).exp() - 1.0)).abs() < 1e-9);
+        assert!((flat.bond_price(flat.par_rate(10.0), 10.0) - 100.0).abs() < 1e-9);
+        // Par yields round-trip through the bootstrapped zero coupon yields (up to the interpolation of the par yields between the tenors)
+        for curve in [curve.clone(), cubic] {
+            let par: YieldCurve = curve.to_par();
+            assert_eq!(par.curve_type(), CurveType::Par);
+            for point in curve.points() {
+                assert!((par.zero_rate(point.tenor) - point.rate).abs() < 1e-2);
+            }
+        }
+        // Forward yields: f(t) = z(t) + t * z'(t), and z(t) is the average of the forward yields up to t
+        assert!((curve.forward_rate(7.5) - (3.65 + 7.5 * 0.02)).abs() < 1e-12);
+        let forward: YieldCurve = YieldCurve::new(date, CurveType::Forward, &[(5.0, 4.0), (10.0, 5.0)]).unwrap();
+        assert!((forward.zero_rate(5.0) - 4.0).abs() < 1e-9);
+        assert!((forward.zero_rate(10.0) - (4.0 * 5.0 + 4.5 * 5.0) / 10.0).abs() < 1e-6);
+        assert!(YieldCurve::new(date, CurveType::Par, &[]).is_err());
+        assert!(YieldCurve::new(date, CurveType::Par, &[(5.0, 3.6), (5.0, 3.7)]).is_err());
+    }
+
+    #[tokio::test]
+    async fn unit_test_fetch_yield_curve() {
+        use crate::{yield_curve::{YieldCurve, CurveType}, mock::{MockServer, MockResponse}};
+        let server: MockServer = MockServer::start().await;
+        server.route("SeriesCodes=IUDSIZC,IUDMIZC,IUDLIZC", vec![MockResponse::csv(include_str!("../tests/fixtures/synthetic/iudsizc_iudmizc_iudlizc_tn.csv"))]);
+        // Last date has no 20 year yield, so the curve of the previous date is returned
+        let date: NaiveDate = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let curve: YieldCurve = YieldCurve::fetch(&server.client(), CurveType::Zero, &date).await.unwrap();
+        assert_eq!(curve.date(), NaiveDate::from_ymd_opt(2024, 1, 4).unwrap());
+        assert_eq!(curve.rate(20.0), 4.2105);
+        assert!(server.requests()[0].contains("Datefrom=22/Dec/2023&Dateto=05/Jan/2024"));
+    }
+}